@@ -0,0 +1,142 @@
+//! Error types, returned by non-panicking operations of the crate.
+
+use crate::{Cell, Direction, Grid};
+use std::error::Error;
+use std::fmt;
+
+/// `GridError` represents a failure of the operation on `Cell` or `Grid`
+///
+/// # Examples
+///
+/// ```
+/// use grid_math::{GridBuilder, GridError};
+///
+/// let result = GridBuilder::new().width(0).depth(5).build();
+/// assert_eq!(result.unwrap_err(), GridError::EmptyDimension);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridError {
+    /// `width` or `depth` of the `Grid` is < 1
+    EmptyDimension,
+    /// `Grid` does not fit into the `u8` global positions
+    DimensionOverflow,
+    /// `Cell` is not within the `Grid`
+    CellOutOfGrid { cell: Cell, grid: Grid },
+    /// Subgrid is not within the `Grid`
+    SubgridOutOfGrid { subgrid: Grid, grid: Grid },
+    /// `start` of the `Grid` overflows `end` on any axis
+    StartAfterEnd { start: Cell, end: Cell },
+    /// Movement of the `Cell` by `step` in `direction` violates the `Grid` bounds
+    OutOfBounds {
+        cell: Cell,
+        grid: Grid,
+        direction: Direction,
+        step: u8,
+    },
+}
+
+impl fmt::Display for GridError {
+    /// implements display for `GridError`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::GridError;
+    ///
+    /// let error = GridError::EmptyDimension;
+    /// assert_eq!(format!("{error}"), "can't create grid with width < 1 or depth < 1");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::EmptyDimension => write!(f, "can't create grid with width < 1 or depth < 1"),
+            GridError::DimensionOverflow => write!(f, "grid overflows global position bounds"),
+            GridError::CellOutOfGrid { cell, grid } => {
+                write!(f, "cell is not within given grid! cell:{cell}, grid:{grid}")
+            }
+            GridError::SubgridOutOfGrid { subgrid, grid } => {
+                write!(
+                    f,
+                    "subgrid is not within given grid! subgrid:{subgrid}, grid:{grid}"
+                )
+            }
+            GridError::StartAfterEnd { start, end } => {
+                write!(f, "start cell overflows end cell! start:{start}, end:{end}")
+            }
+            GridError::OutOfBounds {
+                cell,
+                grid,
+                direction,
+                step,
+            } => {
+                let bounds = match direction {
+                    Direction::Up => "upper",
+                    Direction::Down => "lower",
+                    Direction::Left => "left",
+                    Direction::Right => "right",
+                };
+                write!(
+                    f,
+                    "this operation will violate grid {bounds} bounds! cell:{cell}, grid:{grid}, step:{step}"
+                )
+            }
+        }
+    }
+}
+
+impl Error for GridError {}
+
+/// `ParseError` represents a failure of building the `GridMap` from the text representation
+///
+/// # Examples
+///
+/// ```
+/// use grid_math::{Grid, GridMap, ParseError};
+///
+/// let grid = Grid::new(3, 3);
+/// let result = GridMap::from_rle(grid, "4#", '.');
+/// assert_eq!(result.unwrap_err(), ParseError::RaggedOrInconsistent);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// Parsed number or size does not fit into the `Grid` bounds
+    DimensionOverflow,
+    /// Parsed data does not match the size of the `Grid`
+    RaggedOrInconsistent,
+    /// Parsed `char` is not recognized by the parser
+    UnknownChar(char),
+    /// Parsed key `Cell` is not within the `Grid`
+    KeyOutsideGrid(Cell),
+}
+
+impl fmt::Display for ParseError {
+    /// implements display for `ParseError`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, ParseError};
+    ///
+    /// let error = ParseError::DimensionOverflow;
+    /// assert_eq!(format!("{error}"), "parsed data overflows grid dimensions");
+    ///
+    /// let error = ParseError::UnknownChar('?');
+    /// assert_eq!(format!("{error}"), "unknown char! char:'?'");
+    ///
+    /// let error = ParseError::KeyOutsideGrid(Cell::new(5, 0));
+    /// assert_eq!(format!("{error}"), "parsed key is not within grid! cell:(5, 0)");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::DimensionOverflow => write!(f, "parsed data overflows grid dimensions"),
+            ParseError::RaggedOrInconsistent => {
+                write!(f, "parsed data is inconsistent with grid dimensions")
+            }
+            ParseError::UnknownChar(value) => write!(f, "unknown char! char:{value:?}"),
+            ParseError::KeyOutsideGrid(cell) => {
+                write!(f, "parsed key is not within grid! cell:{cell}")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
@@ -0,0 +1,671 @@
+//! `Cell<T>` and `Grid<T>` with any unsigned scalar global position, enabled with the `num-traits` feature.
+//!
+//! The crate root [`crate::Cell`] and [`crate::Grid`] use `u8` global positions and provide the full API.
+//! Types of this module support grids larger than 255x255, but only with a subset of operations:
+//!
+//! - `Cell<T>`: `new`, `global_width`, `global_depth`, `within`, `width`, `depth`,
+//!   `saturating_up`, `saturating_down`, `saturating_left`, `saturating_right`,
+//!   `project_up`, `project_down`, `project_left`, `project_right`.
+//! - `Grid<T>`: `new`, `indented`, `start`, `end`, `width`, `depth`, `member`, `cells`.
+//!
+//! Every operation behaves the same as its `u8` counterpart. `u8` types can be converted into the types of this module
+//! with `From`, and back with `TryFrom`, when the global position fits into `u8`.
+//!
+//! # Examples
+//!
+//! ```
+//! use grid_math::generic::{Cell, Grid};
+//!
+//! let grid = Grid::<u16>::indented(1000, 1000, (500, 500));
+//! let cell = Cell::<u16>::new(700, 1200);
+//! assert!(cell.within(grid));
+//! assert_eq!(cell.project_down(grid), Cell::<u16>::new(700, 1499));
+//! ```
+
+use crate::GridError;
+use num_traits::{PrimInt, Unsigned};
+use std::fmt;
+
+/// `Cell<T>` represents the basic unit of `Grid<T>`, where `T` is any unsigned primitive integer
+///
+/// # Examples
+///
+/// ```
+/// use grid_math::generic::{Cell, Grid};
+///
+/// let grid = Grid::<u32>::new(100_000, 100_000);
+/// let cell = Cell::<u32>::new(70_000, 5);
+/// assert_eq!(cell.saturating_up(grid, 10), Cell::<u32>::new(70_000, 0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cell<T> {
+    global_width: T,
+    global_depth: T,
+}
+
+/// `Grid<T>` represents the field of `Cell<T>`, where `T` is any unsigned primitive integer
+///
+/// # Examples
+///
+/// ```
+/// use grid_math::generic::Grid;
+///
+/// let grid = Grid::<u16>::new(1000, 300);
+/// assert_eq!((grid.width(), grid.depth()), (1000, 300));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    start: Cell<T>,
+    end: Cell<T>,
+}
+
+impl<T: PrimInt + Unsigned + fmt::Display> Cell<T> {
+    /// Creates new `Cell<T>` with specified `global_width: T` and `global_depth: T` global position
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::Cell;
+    ///
+    /// let cell = Cell::<u16>::new(300, 1000);
+    /// assert_eq!((cell.global_width(), cell.global_depth()), (300, 1000));
+    /// ```
+    pub fn new(global_width: T, global_depth: T) -> Self {
+        Self {
+            global_width,
+            global_depth,
+        }
+    }
+
+    /// Returns `global_width` field of `Cell<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::Cell;
+    ///
+    /// let cell = Cell::<u16>::new(300, 1000);
+    /// assert_eq!(cell.global_width(), 300);
+    /// ```
+    pub fn global_width(self) -> T {
+        self.global_width
+    }
+
+    /// Returns `global_depth` field of `Cell<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::Cell;
+    ///
+    /// let cell = Cell::<u16>::new(300, 1000);
+    /// assert_eq!(cell.global_depth(), 1000);
+    /// ```
+    pub fn global_depth(self) -> T {
+        self.global_depth
+    }
+
+    /// Checks if the `Cell<T>` is within the given `Grid<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::{Cell, Grid};
+    ///
+    /// let grid = Grid::<u16>::new(1000, 1000);
+    /// assert!(Cell::<u16>::new(999, 0).within(grid));
+    /// assert!(!Cell::<u16>::new(1000, 0).within(grid));
+    /// ```
+    pub fn within(self, grid: Grid<T>) -> bool {
+        (grid.start.global_width..=grid.end.global_width).contains(&self.global_width)
+            && (grid.start.global_depth..=grid.end.global_depth).contains(&self.global_depth)
+    }
+
+    /// Checks if the `Cell<T>` is within the given `Grid<T>`, panics if it's not
+    fn within_panic(self, grid: Grid<T>) {
+        if !self.within(grid) {
+            panic!("cell is not within given grid! cell:{self}, grid:{grid}")
+        }
+    }
+
+    /// Calculates the `width` of the `Cell<T>` relative to the given `Grid<T>`
+    ///
+    /// # Panics
+    /// Panics if the `Cell<T>` is not within the given `Grid<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::{Cell, Grid};
+    ///
+    /// let grid = Grid::<u16>::indented(1000, 1000, (300, 300));
+    /// assert_eq!(Cell::<u16>::new(700, 300).width(grid), 400);
+    /// ```
+    pub fn width(self, grid: Grid<T>) -> T {
+        self.within_panic(grid);
+        self.global_width - grid.start.global_width
+    }
+
+    /// Calculates the `depth` of the `Cell<T>` relative to the given `Grid<T>`
+    ///
+    /// # Panics
+    /// Panics if the `Cell<T>` is not within the given `Grid<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::{Cell, Grid};
+    ///
+    /// let grid = Grid::<u16>::indented(1000, 1000, (300, 300));
+    /// assert_eq!(Cell::<u16>::new(700, 1299).depth(grid), 999);
+    /// ```
+    pub fn depth(self, grid: Grid<T>) -> T {
+        self.within_panic(grid);
+        self.global_depth - grid.start.global_depth
+    }
+
+    /// Moves current `Cell<T>` upwards by `step`, returning the upper bound of the `Grid<T>` on overflow
+    ///
+    /// # Panics
+    /// Panics if the `Cell<T>` is not within the given `Grid<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::{Cell, Grid};
+    ///
+    /// let grid = Grid::<u16>::new(1000, 1000);
+    /// let cell = Cell::<u16>::new(500, 500);
+    /// assert_eq!(cell.saturating_up(grid, 200), Cell::<u16>::new(500, 300));
+    /// assert_eq!(cell.saturating_up(grid, 900), Cell::<u16>::new(500, 0));
+    /// ```
+    pub fn saturating_up(self, grid: Grid<T>, step: T) -> Self {
+        let global_depth = if step > self.depth(grid) {
+            grid.start.global_depth
+        } else {
+            self.global_depth - step
+        };
+        Cell {
+            global_depth,
+            ..self
+        }
+    }
+
+    /// Moves current `Cell<T>` downwards by `step`, returning the lower bound of the `Grid<T>` on overflow
+    ///
+    /// # Panics
+    /// Panics if the `Cell<T>` is not within the given `Grid<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::{Cell, Grid};
+    ///
+    /// let grid = Grid::<u16>::new(1000, 1000);
+    /// let cell = Cell::<u16>::new(500, 500);
+    /// assert_eq!(cell.saturating_down(grid, 200), Cell::<u16>::new(500, 700));
+    /// assert_eq!(cell.saturating_down(grid, 900), Cell::<u16>::new(500, 999));
+    /// ```
+    pub fn saturating_down(self, grid: Grid<T>, step: T) -> Self {
+        self.within_panic(grid);
+        let global_depth = if step > grid.end.global_depth - self.global_depth {
+            grid.end.global_depth
+        } else {
+            self.global_depth + step
+        };
+        Cell {
+            global_depth,
+            ..self
+        }
+    }
+
+    /// Moves current `Cell<T>` to the left by `step`, returning the left bound of the `Grid<T>` on overflow
+    ///
+    /// # Panics
+    /// Panics if the `Cell<T>` is not within the given `Grid<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::{Cell, Grid};
+    ///
+    /// let grid = Grid::<u16>::new(1000, 1000);
+    /// let cell = Cell::<u16>::new(500, 500);
+    /// assert_eq!(cell.saturating_left(grid, 200), Cell::<u16>::new(300, 500));
+    /// assert_eq!(cell.saturating_left(grid, 900), Cell::<u16>::new(0, 500));
+    /// ```
+    pub fn saturating_left(self, grid: Grid<T>, step: T) -> Self {
+        let global_width = if step > self.width(grid) {
+            grid.start.global_width
+        } else {
+            self.global_width - step
+        };
+        Cell {
+            global_width,
+            ..self
+        }
+    }
+
+    /// Moves current `Cell<T>` to the right by `step`, returning the right bound of the `Grid<T>` on overflow
+    ///
+    /// # Panics
+    /// Panics if the `Cell<T>` is not within the given `Grid<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::{Cell, Grid};
+    ///
+    /// let grid = Grid::<u16>::new(1000, 1000);
+    /// let cell = Cell::<u16>::new(500, 500);
+    /// assert_eq!(cell.saturating_right(grid, 200), Cell::<u16>::new(700, 500));
+    /// assert_eq!(cell.saturating_right(grid, 900), Cell::<u16>::new(999, 500));
+    /// ```
+    pub fn saturating_right(self, grid: Grid<T>, step: T) -> Self {
+        self.within_panic(grid);
+        let global_width = if step > grid.end.global_width - self.global_width {
+            grid.end.global_width
+        } else {
+            self.global_width + step
+        };
+        Cell {
+            global_width,
+            ..self
+        }
+    }
+
+    /// Projects current `Cell<T>` onto the top side of the given `Grid<T>`
+    ///
+    /// # Panics
+    /// Panics if the `Cell<T>` is not within the given `Grid<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::{Cell, Grid};
+    ///
+    /// let grid = Grid::<u16>::indented(1000, 1000, (300, 300));
+    /// assert_eq!(Cell::<u16>::new(500, 500).project_up(grid), Cell::<u16>::new(500, 300));
+    /// ```
+    pub fn project_up(self, grid: Grid<T>) -> Self {
+        self.saturating_up(grid, T::max_value())
+    }
+
+    /// Projects current `Cell<T>` onto the bottom side of the given `Grid<T>`
+    ///
+    /// # Panics
+    /// Panics if the `Cell<T>` is not within the given `Grid<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::{Cell, Grid};
+    ///
+    /// let grid = Grid::<u16>::indented(1000, 1000, (300, 300));
+    /// assert_eq!(Cell::<u16>::new(500, 500).project_down(grid), Cell::<u16>::new(500, 1299));
+    /// ```
+    pub fn project_down(self, grid: Grid<T>) -> Self {
+        self.saturating_down(grid, T::max_value())
+    }
+
+    /// Projects current `Cell<T>` onto the left side of the given `Grid<T>`
+    ///
+    /// # Panics
+    /// Panics if the `Cell<T>` is not within the given `Grid<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::{Cell, Grid};
+    ///
+    /// let grid = Grid::<u16>::indented(1000, 1000, (300, 300));
+    /// assert_eq!(Cell::<u16>::new(500, 500).project_left(grid), Cell::<u16>::new(300, 500));
+    /// ```
+    pub fn project_left(self, grid: Grid<T>) -> Self {
+        self.saturating_left(grid, T::max_value())
+    }
+
+    /// Projects current `Cell<T>` onto the right side of the given `Grid<T>`
+    ///
+    /// # Panics
+    /// Panics if the `Cell<T>` is not within the given `Grid<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::{Cell, Grid};
+    ///
+    /// let grid = Grid::<u16>::indented(1000, 1000, (300, 300));
+    /// assert_eq!(Cell::<u16>::new(500, 500).project_right(grid), Cell::<u16>::new(1299, 500));
+    /// ```
+    pub fn project_right(self, grid: Grid<T>) -> Self {
+        self.saturating_right(grid, T::max_value())
+    }
+}
+
+impl<T: PrimInt + Unsigned + fmt::Display> Grid<T> {
+    /// Creates new `Grid<T>` with specified `width: T` and `depth: T`, starting at (0,0)
+    ///
+    /// # Panics
+    /// Panics if `width` or `depth` parameters < 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::Grid;
+    ///
+    /// let grid = Grid::<u16>::new(1000, 1000);
+    /// assert_eq!(format!("{grid}"), "[(0, 0):(999, 999)]");
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::generic::Grid;
+    ///
+    /// let grid = Grid::<u16>::new(0, 1000); // panic!
+    /// ```
+    pub fn new(width: T, depth: T) -> Self {
+        Grid::indented(width, depth, (T::zero(), T::zero()))
+    }
+
+    /// Creates new `Grid<T>` with specified `width: T` and `depth: T`, starting at indent
+    ///
+    /// # Panics
+    /// Panics if `width` or `depth` parameters < 1
+    /// Panics if the `Grid<T>` overflows `T::max_value()` global position
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::Grid;
+    ///
+    /// let grid = Grid::<u16>::indented(1000, 1000, (300, 300));
+    /// assert_eq!(format!("{grid}"), "[(300, 300):(1299, 1299)]");
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::generic::Grid;
+    ///
+    /// let grid = Grid::<u16>::indented(1000, 1000, (u16::MAX - 500, 0)); // panic!
+    /// ```
+    pub fn indented(width: T, depth: T, indent: (T, T)) -> Self {
+        if width < T::one() || depth < T::one() {
+            panic!("can't create grid with width < 1 or depth < 1")
+        }
+        let end = |indent: T, size: T| {
+            if size - T::one() > T::max_value() - indent {
+                panic!("grid overflows global position bounds! indent:{indent}, size:{size}")
+            }
+            indent + (size - T::one())
+        };
+        Grid {
+            start: Cell {
+                global_width: indent.0,
+                global_depth: indent.1,
+            },
+            end: Cell {
+                global_width: end(indent.0, width),
+                global_depth: end(indent.1, depth),
+            },
+        }
+    }
+
+    /// Returns `start` of the `Grid<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::{Cell, Grid};
+    ///
+    /// let grid = Grid::<u16>::indented(1000, 1000, (300, 300));
+    /// assert_eq!(grid.start(), Cell::<u16>::new(300, 300));
+    /// ```
+    pub fn start(self) -> Cell<T> {
+        self.start
+    }
+
+    /// Returns `end` of the `Grid<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::{Cell, Grid};
+    ///
+    /// let grid = Grid::<u16>::indented(1000, 1000, (300, 300));
+    /// assert_eq!(grid.end(), Cell::<u16>::new(1299, 1299));
+    /// ```
+    pub fn end(self) -> Cell<T> {
+        self.end
+    }
+
+    /// Calculates `width` of the `Grid<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::Grid;
+    ///
+    /// let grid = Grid::<u32>::indented(70_000, 10, (5, 5));
+    /// assert_eq!(grid.width(), 70_000);
+    /// ```
+    pub fn width(self) -> T {
+        self.end.global_width - self.start.global_width + T::one()
+    }
+
+    /// Calculates `depth` of the `Grid<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::Grid;
+    ///
+    /// let grid = Grid::<u32>::indented(10, 70_000, (5, 5));
+    /// assert_eq!(grid.depth(), 70_000);
+    /// ```
+    pub fn depth(self) -> T {
+        self.end.global_depth - self.start.global_depth + T::one()
+    }
+
+    /// Returns new `Cell<T>` by `width: T` and `depth: T` relative to the `Grid<T>`
+    ///
+    /// # Panics
+    /// Panics if the requested `Cell<T>` is not within the `Grid<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::{Cell, Grid};
+    ///
+    /// let grid = Grid::<u16>::indented(1000, 1000, (300, 300));
+    /// assert_eq!(grid.member(700, 0), Cell::<u16>::new(1000, 300));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::generic::Grid;
+    ///
+    /// let grid = Grid::<u16>::new(1000, 1000);
+    /// grid.member(1000, 0); // panic!
+    /// ```
+    pub fn member(self, width: T, depth: T) -> Cell<T> {
+        if width >= self.width() || depth >= self.depth() {
+            panic!("member is not within given grid! width:{width}, depth:{depth}, grid:{self}")
+        }
+        Cell {
+            global_width: self.start.global_width + width,
+            global_depth: self.start.global_depth + depth,
+        }
+    }
+
+    /// Returns an iterator over every `Cell<T>` of the `Grid<T>`, row by row from the `start`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::{Cell, Grid};
+    ///
+    /// let grid = Grid::<u16>::indented(2, 2, (1000, 1000));
+    /// let cells: Vec<Cell<u16>> = grid.cells().collect();
+    /// assert_eq!(cells, vec![
+    ///     Cell::<u16>::new(1000, 1000),
+    ///     Cell::<u16>::new(1001, 1000),
+    ///     Cell::<u16>::new(1000, 1001),
+    ///     Cell::<u16>::new(1001, 1001),
+    /// ]);
+    /// ```
+    pub fn cells(self) -> impl Iterator<Item = Cell<T>> {
+        std::iter::successors(Some(self.start), move |cell| {
+            if cell.global_width < self.end.global_width {
+                Some(Cell {
+                    global_width: cell.global_width + T::one(),
+                    ..*cell
+                })
+            } else if cell.global_depth < self.end.global_depth {
+                Some(Cell {
+                    global_width: self.start.global_width,
+                    global_depth: cell.global_depth + T::one(),
+                })
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl<T: PrimInt + Unsigned> From<crate::Cell> for Cell<T> {
+    /// Creates new `Cell<T>` with the same global position as the `u8` `Cell`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::generic;
+    /// use grid_math::Cell;
+    ///
+    /// let cell: generic::Cell<u16> = Cell::new(5, 250).into();
+    /// assert_eq!(cell, generic::Cell::<u16>::new(5, 250));
+    /// ```
+    fn from(cell: crate::Cell) -> Self {
+        Self {
+            global_width: T::from(cell.global_width()).unwrap(),
+            global_depth: T::from(cell.global_depth()).unwrap(),
+        }
+    }
+}
+
+impl<T: PrimInt + Unsigned> From<crate::Grid> for Grid<T> {
+    /// Creates new `Grid<T>` with the same bounds as the `u8` `Grid`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::generic;
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let small = Grid::indented(5, 5, (2, 2));
+    /// let grid: generic::Grid<u16> = small.into();
+    /// assert_eq!(grid, generic::Grid::<u16>::indented(5, 5, (2, 2)));
+    ///
+    /// // operations of the subset match the `u8` counterparts:
+    /// let cell = Cell::new(3, 5);
+    /// assert_eq!(
+    ///     generic::Cell::<u16>::from(cell).saturating_up(grid, 2),
+    ///     cell.saturating_up(small, 2).into()
+    /// );
+    /// ```
+    fn from(grid: crate::Grid) -> Self {
+        Self {
+            start: grid.start().into(),
+            end: grid.end().into(),
+        }
+    }
+}
+
+impl<T: PrimInt + Unsigned> TryFrom<Cell<T>> for crate::Cell {
+    type Error = GridError;
+
+    /// Creates new `u8` `Cell` with the same global position as the `Cell<T>`
+    ///
+    /// # Errors
+    /// Returns `GridError::DimensionOverflow`, if the global position does not fit into `u8`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::generic;
+    /// use grid_math::{Cell, GridError};
+    ///
+    /// assert_eq!(Cell::try_from(generic::Cell::<u16>::new(5, 250)), Ok(Cell::new(5, 250)));
+    /// assert_eq!(
+    ///     Cell::try_from(generic::Cell::<u16>::new(5, 256)),
+    ///     Err(GridError::DimensionOverflow)
+    /// );
+    /// ```
+    fn try_from(cell: Cell<T>) -> Result<Self, Self::Error> {
+        match (cell.global_width.to_u8(), cell.global_depth.to_u8()) {
+            (Some(global_width), Some(global_depth)) => {
+                Ok(crate::Cell::new(global_width, global_depth))
+            }
+            _ => Err(GridError::DimensionOverflow),
+        }
+    }
+}
+
+impl<T: PrimInt + Unsigned> TryFrom<Grid<T>> for crate::Grid {
+    type Error = GridError;
+
+    /// Creates new `u8` `Grid` with the same bounds as the `Grid<T>`
+    ///
+    /// # Errors
+    /// Returns `GridError::DimensionOverflow`, if the bounds do not fit into `u8` `Grid`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::generic;
+    /// use grid_math::{Grid, GridError};
+    ///
+    /// let grid = generic::Grid::<u16>::indented(5, 5, (2, 2));
+    /// assert_eq!(Grid::try_from(grid), Ok(Grid::indented(5, 5, (2, 2))));
+    ///
+    /// let grid = generic::Grid::<u16>::new(1000, 5);
+    /// assert_eq!(Grid::try_from(grid), Err(GridError::DimensionOverflow));
+    /// ```
+    fn try_from(grid: Grid<T>) -> Result<Self, Self::Error> {
+        crate::Grid::checked_from_bounds(grid.start.try_into()?, grid.end.try_into()?)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Cell<T> {
+    /// implements display for `Cell<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::Cell;
+    ///
+    /// let cell = Cell::<u16>::new(500, 600);
+    /// assert_eq!(format!("{cell}"), "(500, 600)");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "({w}, {d})",
+            w = self.global_width,
+            d = self.global_depth
+        )
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    /// implements display for `Grid<T>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::generic::Grid;
+    ///
+    /// let grid = Grid::<u16>::new(1000, 1000);
+    /// assert_eq!(format!("{grid}"), "[(0, 0):(999, 999)]");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{start}:{end}]", start = self.start, end = self.end)
+    }
+}
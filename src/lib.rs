@@ -15,11 +15,19 @@
 //!
 //! # Note
 //!
-//! - `Cell`'s global position currently represented in the `u8` for simplicity,
-//!   and because this is enough for most terminal games. This may be changed to be a scalar generic in the future.
-//! - Error handling is currently rather stupid (just checks with panic!), but this helps to prevent scary logical bugs.
+//! - `Cell`'s global position is represented in the `u8` by default for simplicity,
+//!   and because this is enough for most terminal games. Larger grids with wider unsigned scalars
+//!   are supported by a subset of operations in the `generic` module, enabled with the `num-traits` feature.
+//! - Error handling is mostly done with panic!, which helps to prevent scary logical bugs.
+//!   Some operations have non-panicking counterparts, returning [`GridError`].
 //! - Crate is in the "work in progress" state, so the public API may change in the future. Feel free to contribute!
 //!
+//! # Features
+//!
+//! - `rand` (enabled by default): random `Cell` selection for `Grid` and `GridMap<V>`.
+//! - `num-traits`: `Cell<T>` and `Grid<T>` with any unsigned scalar in the `generic` module.
+//! - `serde`: implements `Serialize` and `Deserialize` for `Cell`, `Grid`, and `GridMap<V>`.
+//!
 //! # Examples
 //!
 //! Perform some basic calculations for `Cell`:
@@ -72,13 +80,22 @@
 //! assert_eq!(map.get(&Cell::new(0, 0)).unwrap(), &'#');
 //! ```
 
-use rand::seq::IteratorRandom;
-//use std::cmp::Ordering;
-use std::collections::HashMap;
+mod error;
+#[cfg(feature = "num-traits")]
+pub mod generic;
+#[cfg(feature = "serde")]
+mod serialize;
+
+pub use error::{GridError, ParseError};
+
+#[cfg(feature = "rand")]
+use rand::{Rng, seq::IteratorRandom};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
 use std::convert::{From, Into};
 use std::fmt;
-use std::iter::Filter;
-use std::ops::{Deref, DerefMut};
+use std::iter::{Filter, Map, TakeWhile};
+use std::ops::{Add, Deref, DerefMut, Sub};
 
 /// `Cell` represents the basic unit of `Grid`.
 ///
@@ -183,8 +200,14 @@ use std::ops::{Deref, DerefMut};
 ///
 /// To get more examples, look at `Cell` and `Grid` methods documentation.
 ///
+/// # Ordering
+/// `Cell`s are ordered in the row-major (scanline) order: by `global_depth` first, and then by `global_width`.
+/// This is the same order, in which `Cells` iterator yields `Cell`s, so sorting gives deterministic scanline output.
+/// Note that this is the opposite of comparing `(global_width, global_depth)` tuples
+///
 ///
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Cell {
     global_width: u8,
     global_depth: u8,
@@ -354,11 +377,53 @@ pub struct Cell {
 ///
 ///
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "serialize::RawGrid"))]
 pub struct Grid {
     start: Cell,
     end: Cell,
 }
 
+/// `GridBuilder` represents a step by step constructor of `Grid`
+///
+/// Unlike `Grid::new` and `Grid::indented`, `GridBuilder` does not panic on invalid parameters,
+/// instead `build` returns `GridError`
+///
+/// # Examples
+///
+/// ```
+/// use grid_math::{Grid, GridBuilder};
+///
+/// let grid = GridBuilder::new().width(5).depth(3).indent((1, 2)).build().unwrap();
+/// assert_eq!(grid, Grid::indented(5, 3, (1, 2)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GridBuilder {
+    width: u8,
+    depth: u8,
+    indent: (u8, u8),
+}
+
+/// `TorusGrid` represents a wrapper around the `Grid`, that wraps around both axes,
+/// so moving off one edge of the `Grid` lands on the opposite edge
+///
+/// All movement operations of `TorusGrid` are built on top of the `overflowing_*` operations of `Cell`,
+/// and the `overflowing_*` operations of `TorusGrid` expose whether the move wrapped around the `Grid`
+///
+/// # Examples
+///
+/// ```
+/// use grid_math::{Cell, Grid, TorusGrid};
+///
+/// let torus = TorusGrid::from(Grid::new(5, 5));
+/// assert_eq!(torus.right(Cell::new(4, 2), 1), Cell::new(0, 2));
+/// assert_eq!(torus.up(Cell::new(1, 0), 2), Cell::new(1, 3));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TorusGrid {
+    grid: Grid,
+}
+
 /// `Cells` represents an iterator over every `Cell` on the `Grid`
 ///
 /// # Examples
@@ -461,6 +526,134 @@ pub struct Columns {
     consumed: bool,
 }
 
+/// `Ring` represents an iterator over every `Cell` on the perimeter of the `Grid`
+///
+/// Cells go clockwise, starting at the `Grid` start, and every `Cell` is returned exactly once
+///
+/// # Examples
+///
+/// Get every `Cell` on the frame of `Grid`:
+/// ```
+/// use grid_math::{Cell, Grid};
+///
+/// let grid = Grid::new(3, 3);
+///
+/// let frame: Vec<Cell> = grid.ring().collect();
+///
+/// assert_eq!(frame, vec![
+///     Cell::new(0, 0),
+///     Cell::new(1, 0),
+///     Cell::new(2, 0),
+///     Cell::new(2, 1),
+///     Cell::new(2, 2),
+///     Cell::new(1, 2),
+///     Cell::new(0, 2),
+///     Cell::new(0, 1),
+/// ]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ring {
+    grid: Grid,
+    current: Cell,
+    consumed: bool,
+}
+
+/// `Spiral` represents an iterator over every `Cell` on the `Grid`, going in the inward clockwise spiral
+///
+/// Cells go clockwise along the perimeter of the `Grid`, starting at the `Grid` start,
+/// and then along the perimeters of the inner subgrids, until the center is reached
+///
+/// # Examples
+///
+/// ```
+/// use grid_math::{Cell, Grid};
+///
+/// let grid = Grid::new(3, 3);
+///
+/// let spiral: Vec<Cell> = grid.spiral().collect();
+///
+/// assert_eq!(spiral, vec![
+///     Cell::new(0, 0),
+///     Cell::new(1, 0),
+///     Cell::new(2, 0),
+///     Cell::new(2, 1),
+///     Cell::new(2, 2),
+///     Cell::new(1, 2),
+///     Cell::new(0, 2),
+///     Cell::new(0, 1),
+///     Cell::new(1, 1),
+/// ]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Spiral {
+    layer: Grid,
+    ring: Ring,
+    consumed: bool,
+}
+
+/// `LineIter` represents an iterator over every `Cell` on the straight line between two `Cell`s,
+/// calculated with Bresenham's line algorithm
+///
+/// Cells go from the first `Cell` to the second one inclusive, in global space
+///
+/// # Examples
+///
+/// ```
+/// use grid_math::Cell;
+///
+/// let line: Vec<Cell> = Cell::new(0, 0).line_to(Cell::new(4, 2)).collect();
+///
+/// assert_eq!(line, vec![
+///     Cell::new(0, 0),
+///     Cell::new(1, 1),
+///     Cell::new(2, 1),
+///     Cell::new(3, 2),
+///     Cell::new(4, 2),
+/// ]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineIter {
+    current: Cell,
+    end: Cell,
+    width_delta: i16,
+    depth_delta: i16,
+    width_step: i16,
+    depth_step: i16,
+    error: i16,
+    consumed: bool,
+}
+
+/// `BorderCells` represents an iterator over every `Cell` on the perimeter of the `Grid`,
+/// paired with the `Edge` the `Cell` belongs to
+///
+/// Cells go in the same order as in the `Ring`, and `Cell`s belonging to two borders are tagged as `Edge::Corner`
+///
+/// # Examples
+///
+/// ```
+/// use grid_math::{Cell, Corner, Edge, Grid};
+///
+/// let grid = Grid::new(3, 3);
+///
+/// let border: Vec<(Cell, Edge)> = grid.border_cells().collect();
+///
+/// assert_eq!(border, vec![
+///     (Cell::new(0, 0), Edge::Corner(Corner::TopLeft)),
+///     (Cell::new(1, 0), Edge::Top),
+///     (Cell::new(2, 0), Edge::Corner(Corner::TopRight)),
+///     (Cell::new(2, 1), Edge::Right),
+///     (Cell::new(2, 2), Edge::Corner(Corner::BottomRight)),
+///     (Cell::new(1, 2), Edge::Bottom),
+///     (Cell::new(0, 2), Edge::Corner(Corner::BottomLeft)),
+///     (Cell::new(0, 1), Edge::Left),
+/// ]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BorderCells {
+    grid: Grid,
+    ring: Ring,
+}
+
 /// `GridMap<V>` represents a wrapper around the `HashMap<Cell, V>`
 ///
 /// `GridMap` is helpful for storing some actual data on the `Grid`.
@@ -499,6 +692,203 @@ pub struct GridMap<V> {
     hashmap: HashMap<Cell, V>,
 }
 
+/// `CellDiff<V>` represents a change of a single `Cell` between two `GridMap`s
+///
+/// `Added` holds the new value, `Removed` holds the old value,
+/// and `Changed` holds both the old and the new values
+///
+/// # Examples
+///
+/// ```
+/// use grid_math::{Cell, CellDiff, GridMap};
+///
+/// let mut before: GridMap<char> = GridMap::new(3, 3);
+/// before.insert(Cell::new(0, 0), '#');
+/// let mut after = before.clone();
+/// after.insert(Cell::new(0, 0), '@');
+///
+/// assert_eq!(before.diff(&after), vec![(Cell::new(0, 0), CellDiff::Changed(&'#', &'@'))]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CellDiff<V> {
+    Added(V),
+    Removed(V),
+    Changed(V, V),
+}
+
+/// `Axis` represents one of the two axis of `Grid`: `width` or `depth`
+///
+/// # Examples
+///
+/// ```
+/// use grid_math::{Axis, Cell, Grid};
+///
+/// let grid = Grid::new(5, 5);
+/// let cells = grid.symmetrize(&[Cell::new(1, 2)], Axis::Width);
+/// assert_eq!(cells, vec![Cell::new(1, 2), Cell::new(3, 2)]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    Width,
+    Depth,
+}
+
+/// `DistanceMetric` represents the way of measuring distance between two `Cell`s
+///
+/// `Manhattan` is the sum of steps on `width` and `depth` axis,
+/// `Chebyshev` is the max of steps on `width` and `depth` axis
+///
+/// # Examples
+///
+/// ```
+/// use grid_math::{Cell, DistanceMetric};
+///
+/// let (first, second) = (Cell::new(1, 1), Cell::new(3, 4));
+/// assert_eq!(first.distance(second, DistanceMetric::Manhattan), 5);
+/// assert_eq!(first.distance(second, DistanceMetric::Chebyshev), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DistanceMetric {
+    Manhattan,
+    Chebyshev,
+}
+
+/// `Direction` represents the direction of movement on the `Grid`
+///
+/// # Examples
+///
+/// ```
+/// use grid_math::{Cell, Direction, Grid};
+///
+/// let grid = Grid::new(10, 10);
+/// let cell = Cell::new(1, 5);
+/// assert_eq!(cell.nearest_edge(grid), Direction::Left);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// `Heading` represents one of the eight directions from the `Cell` to its orthogonal and diagonal neighbors
+///
+/// # Examples
+///
+/// ```
+/// use grid_math::{Cell, Heading};
+///
+/// let cell = Cell::new(5, 5);
+/// assert_eq!(cell.direction_to(Cell::new(7, 2)), Some(Heading::UpRight));
+/// assert_eq!(Heading::UpRight.offset(), (1, -1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Heading {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl Heading {
+    /// Every `Heading`, orthogonal ones first: `up`, `down`, `left`, `right`,
+    /// `up-left`, `up-right`, `down-left`, `down-right`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Heading;
+    ///
+    /// assert_eq!(Heading::ALL.len(), 8);
+    /// assert_eq!(Heading::ALL[..4], [Heading::Up, Heading::Down, Heading::Left, Heading::Right]);
+    /// ```
+    pub const ALL: [Heading; 8] = [
+        Heading::Up,
+        Heading::Down,
+        Heading::Left,
+        Heading::Right,
+        Heading::UpLeft,
+        Heading::UpRight,
+        Heading::DownLeft,
+        Heading::DownRight,
+    ];
+
+    /// Returns `width` and `depth` offset of the one step in the `Heading`:
+    /// negative steps go left and up, positive steps go right and down
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Heading;
+    ///
+    /// assert_eq!(Heading::Up.offset(), (0, -1));
+    /// assert_eq!(Heading::DownLeft.offset(), (-1, 1));
+    /// ```
+    pub const fn offset(self) -> (i8, i8) {
+        match self {
+            Heading::Up => (0, -1),
+            Heading::Down => (0, 1),
+            Heading::Left => (-1, 0),
+            Heading::Right => (1, 0),
+            Heading::UpLeft => (-1, -1),
+            Heading::UpRight => (1, -1),
+            Heading::DownLeft => (-1, 1),
+            Heading::DownRight => (1, 1),
+        }
+    }
+}
+
+/// `Corner` represents one of the four corners of the `Grid`
+///
+/// # Examples
+///
+/// ```
+/// use grid_math::{Cell, Corner, Edge, Grid};
+///
+/// let grid = Grid::new(5, 5);
+/// let (cell, edge) = grid.border_cells().next().unwrap();
+/// assert_eq!(cell, Cell::new(0, 0));
+/// assert_eq!(edge, Edge::Corner(Corner::TopLeft));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Corner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// `Edge` represents the border of the `Grid` that the perimeter `Cell` belongs to
+///
+/// `Cell`s that belong to two borders are represented with `Edge::Corner`
+///
+/// # Examples
+///
+/// ```
+/// use grid_math::{Cell, Edge, Grid};
+///
+/// let grid = Grid::new(5, 5);
+/// let top: Vec<Cell> = grid
+///     .border_cells()
+///     .filter(|&(_, edge)| edge == Edge::Top)
+///     .map(|(cell, _)| cell)
+///     .collect();
+/// assert_eq!(top, vec![Cell::new(1, 0), Cell::new(2, 0), Cell::new(3, 0)]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Edge {
+    Top,
+    Bottom,
+    Left,
+    Right,
+    Corner(Corner),
+}
+
 impl Cell {
     /// Creates new `Cell` with specified `global_width: u8` and `global_depth: u8` global position
     ///
@@ -509,7 +899,7 @@ impl Cell {
     ///
     /// let cell = Cell::new(10, 15);
     /// ```
-    pub fn new(global_width: u8, global_depth: u8) -> Self {
+    pub const fn new(global_width: u8, global_depth: u8) -> Self {
         Self {
             global_width,
             global_depth,
@@ -617,8 +1007,35 @@ impl Cell {
     /// cell.within_panic(grid);
     /// ```
     pub fn within_panic(self, grid: Grid) {
-        if !self.within(grid) {
-            panic!("cell is not within given grid! cell:{self}, grid:{grid}")
+        if let Err(error) = self.checked_within(grid) {
+            panic!("{error}")
+        }
+    }
+
+    /// Checks if the `Cell` is within the given `Grid`
+    ///
+    /// This is a non-panicking counterpart of the `within_panic` method
+    ///
+    /// # Errors
+    /// Returns `GridError::CellOutOfGrid`, if the `Cell` is not within the given `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid, GridError};
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let cell = Cell::new(9, 9);
+    /// assert_eq!(cell.checked_within(grid), Ok(()));
+    ///
+    /// let cell = Cell::new(9, 15);
+    /// assert_eq!(cell.checked_within(grid), Err(GridError::CellOutOfGrid { cell, grid }));
+    /// ```
+    pub fn checked_within(self, grid: Grid) -> Result<(), GridError> {
+        if self.within(grid) {
+            Ok(())
+        } else {
+            Err(GridError::CellOutOfGrid { cell: self, grid })
         }
     }
 
@@ -633,7 +1050,7 @@ impl Cell {
     /// let w = cell.global_width();
     /// assert_eq!(w, 8);
     /// ```
-    pub fn global_width(self) -> u8 {
+    pub const fn global_width(self) -> u8 {
         self.global_width
     }
 
@@ -648,7 +1065,7 @@ impl Cell {
     /// let d = cell.global_depth();
     /// assert_eq!(d, 8);
     /// ```
-    pub fn global_depth(self) -> u8 {
+    pub const fn global_depth(self) -> u8 {
         self.global_depth
     }
 
@@ -818,114 +1235,261 @@ impl Cell {
     /// This operation does not mutate current `Cell` fields,
     /// instead it calculates new position and returns new `Cell`
     ///
-    /// # Panics
-    /// Panics if the `Cell` is not within the given `Grid`
-    /// Panics if this operation will violate the given `Grid` upper border
+    /// This is a non-panicking counterpart of the `strict_up` method
+    ///
+    /// # Errors
+    /// Returns `GridError::CellOutOfGrid`, if the `Cell` is not within the given `Grid`
+    /// Returns `GridError::OutOfBounds`, if this operation will violate the given `Grid` upper border
     ///
     /// # Examples
     ///
     /// ```
-    /// use grid_math::{Cell, Grid};
+    /// use grid_math::{Cell, Direction, Grid, GridError};
     ///
     /// let grid = Grid::new(10, 10);
     /// let cell = Cell::new(2, 2);
-    /// let next = cell.strict_up(grid, 2);
-    /// assert_eq!(next, Cell::new(2, 0));
-    /// ```
-    ///
-    /// ```should_panic
-    /// use grid_math::{Cell, Grid};
+    /// assert_eq!(cell.try_up(grid, 2), Ok(Cell::new(2, 0)));
+    /// assert_eq!(
+    ///     cell.try_up(grid, 3),
+    ///     Err(GridError::OutOfBounds { cell, grid, direction: Direction::Up, step: 3 })
+    /// );
     ///
-    /// let grid = Grid::new(10, 10);
-    /// let cell = Cell::new(2, 2);
-    /// let next = cell.strict_up(grid, 3); // panic!
+    /// let cell = Cell::new(12, 12);
+    /// assert_eq!(cell.try_up(grid, 1), Err(GridError::CellOutOfGrid { cell, grid }));
     /// ```
-    pub fn strict_up(self, grid: Grid, step: u8) -> Cell {
+    pub fn try_up(self, grid: Grid, step: u8) -> Result<Cell, GridError> {
+        self.checked_within(grid)?;
         if self.will_underflow_depth(grid, step) {
-            panic!(
-                "this operation will violate grid upper bounds! cell:{self}, grid:{grid}, step:{step}"
-            );
+            return Err(GridError::OutOfBounds {
+                cell: self,
+                grid,
+                direction: Direction::Up,
+                step,
+            });
         }
-        Cell {
+        Ok(Cell {
             global_width: self.global_width,
             global_depth: self.global_depth - step,
-        }
+        })
     }
 
     /// Moves current `Cell` downwards by `step` relative to the given `Grid`
     /// This operation does not mutate current `Cell` fields,
     /// instead it calculates new position and returns new `Cell`
     ///
-    /// # Panics
-    /// Panics if the `Cell` is not within the given `Grid`
-    /// Panics if this operation will violate the given `Grid` lower border
+    /// This is a non-panicking counterpart of the `strict_down` method
+    ///
+    /// # Errors
+    /// Returns `GridError::CellOutOfGrid`, if the `Cell` is not within the given `Grid`
+    /// Returns `GridError::OutOfBounds`, if this operation will violate the given `Grid` lower border
     ///
     /// # Examples
     ///
     /// ```
-    /// use grid_math::{Cell, Grid};
+    /// use grid_math::{Cell, Direction, Grid, GridError};
     ///
     /// let grid = Grid::new(10, 10);
     /// let cell = Cell::new(7, 7);
-    /// let next = cell.strict_down(grid, 2);
-    /// assert_eq!(next, Cell::new(7, 9));
-    /// ```
-    ///
-    /// ```should_panic
-    /// use grid_math::{Cell, Grid};
+    /// assert_eq!(cell.try_down(grid, 2), Ok(Cell::new(7, 9)));
+    /// assert_eq!(
+    ///     cell.try_down(grid, 3),
+    ///     Err(GridError::OutOfBounds { cell, grid, direction: Direction::Down, step: 3 })
+    /// );
     ///
-    /// let grid = Grid::new(10, 10);
-    /// let cell = Cell::new(7, 7);
-    /// let next = cell.strict_down(grid, 3); // panic!
+    /// let cell = Cell::new(12, 12);
+    /// assert_eq!(cell.try_down(grid, 1), Err(GridError::CellOutOfGrid { cell, grid }));
     /// ```
-    pub fn strict_down(self, grid: Grid, step: u8) -> Cell {
+    pub fn try_down(self, grid: Grid, step: u8) -> Result<Cell, GridError> {
+        self.checked_within(grid)?;
         if self.will_overflow_depth(grid, step) {
-            panic!(
-                "this operation will violate grid lower bounds! cell:{self}, grid:{grid}, step:{step}"
-            );
+            return Err(GridError::OutOfBounds {
+                cell: self,
+                grid,
+                direction: Direction::Down,
+                step,
+            });
         }
-        Cell {
+        Ok(Cell {
             global_width: self.global_width,
             global_depth: self.global_depth + step,
-        }
+        })
     }
 
     /// Moves current `Cell` to the left by `step` relative to the given `Grid`
     /// This operation does not mutate current `Cell` fields,
     /// instead it calculates new position and returns new `Cell`
     ///
-    /// # Panics
-    /// Panics if the `Cell` is not within the given `Grid`
-    /// Panics if this operation will violate the given `Grid` left border
+    /// This is a non-panicking counterpart of the `strict_left` method
+    ///
+    /// # Errors
+    /// Returns `GridError::CellOutOfGrid`, if the `Cell` is not within the given `Grid`
+    /// Returns `GridError::OutOfBounds`, if this operation will violate the given `Grid` left border
     ///
     /// # Examples
     ///
     /// ```
-    /// use grid_math::{Cell, Grid};
+    /// use grid_math::{Cell, Direction, Grid, GridError};
     ///
     /// let grid = Grid::new(10, 10);
     /// let cell = Cell::new(2, 2);
-    /// let next = cell.strict_left(grid, 2);
-    /// assert_eq!(next, Cell::new(0, 2));
-    /// ```
-    ///
-    /// ```should_panic
-    /// use grid_math::{Cell, Grid};
+    /// assert_eq!(cell.try_left(grid, 2), Ok(Cell::new(0, 2)));
+    /// assert_eq!(
+    ///     cell.try_left(grid, 3),
+    ///     Err(GridError::OutOfBounds { cell, grid, direction: Direction::Left, step: 3 })
+    /// );
     ///
-    /// let grid = Grid::new(10, 10);
-    /// let cell = Cell::new(2, 2);
-    /// let next = cell.strict_left(grid, 3); // panic!
+    /// let cell = Cell::new(12, 12);
+    /// assert_eq!(cell.try_left(grid, 1), Err(GridError::CellOutOfGrid { cell, grid }));
     /// ```
-    pub fn strict_left(self, grid: Grid, step: u8) -> Cell {
+    pub fn try_left(self, grid: Grid, step: u8) -> Result<Cell, GridError> {
+        self.checked_within(grid)?;
         if self.will_underflow_width(grid, step) {
-            panic!(
-                "this operation will violate grid left bounds! cell:{self}, grid:{grid}, step:{step}"
-            );
+            return Err(GridError::OutOfBounds {
+                cell: self,
+                grid,
+                direction: Direction::Left,
+                step,
+            });
         }
-        Cell {
+        Ok(Cell {
             global_width: self.global_width - step,
             global_depth: self.global_depth,
+        })
+    }
+
+    /// Moves current `Cell` to the right by `step` relative to the given `Grid`
+    /// This operation does not mutate current `Cell` fields,
+    /// instead it calculates new position and returns new `Cell`
+    ///
+    /// This is a non-panicking counterpart of the `strict_right` method
+    ///
+    /// # Errors
+    /// Returns `GridError::CellOutOfGrid`, if the `Cell` is not within the given `Grid`
+    /// Returns `GridError::OutOfBounds`, if this operation will violate the given `Grid` right border
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Direction, Grid, GridError};
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let cell = Cell::new(7, 7);
+    /// assert_eq!(cell.try_right(grid, 2), Ok(Cell::new(9, 7)));
+    /// assert_eq!(
+    ///     cell.try_right(grid, 3),
+    ///     Err(GridError::OutOfBounds { cell, grid, direction: Direction::Right, step: 3 })
+    /// );
+    ///
+    /// let cell = Cell::new(12, 12);
+    /// assert_eq!(cell.try_right(grid, 1), Err(GridError::CellOutOfGrid { cell, grid }));
+    /// ```
+    pub fn try_right(self, grid: Grid, step: u8) -> Result<Cell, GridError> {
+        self.checked_within(grid)?;
+        if self.will_overflow_width(grid, step) {
+            return Err(GridError::OutOfBounds {
+                cell: self,
+                grid,
+                direction: Direction::Right,
+                step,
+            });
         }
+        Ok(Cell {
+            global_width: self.global_width + step,
+            global_depth: self.global_depth,
+        })
+    }
+
+    /// Moves current `Cell` upwards by `step` relative to the given `Grid`
+    /// This operation does not mutate current `Cell` fields,
+    /// instead it calculates new position and returns new `Cell`
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the given `Grid`
+    /// Panics if this operation will violate the given `Grid` upper border
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let cell = Cell::new(2, 2);
+    /// let next = cell.strict_up(grid, 2);
+    /// assert_eq!(next, Cell::new(2, 0));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let cell = Cell::new(2, 2);
+    /// let next = cell.strict_up(grid, 3); // panic!
+    /// ```
+    pub fn strict_up(self, grid: Grid, step: u8) -> Cell {
+        self.try_up(grid, step)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Moves current `Cell` downwards by `step` relative to the given `Grid`
+    /// This operation does not mutate current `Cell` fields,
+    /// instead it calculates new position and returns new `Cell`
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the given `Grid`
+    /// Panics if this operation will violate the given `Grid` lower border
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let cell = Cell::new(7, 7);
+    /// let next = cell.strict_down(grid, 2);
+    /// assert_eq!(next, Cell::new(7, 9));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let cell = Cell::new(7, 7);
+    /// let next = cell.strict_down(grid, 3); // panic!
+    /// ```
+    pub fn strict_down(self, grid: Grid, step: u8) -> Cell {
+        self.try_down(grid, step)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Moves current `Cell` to the left by `step` relative to the given `Grid`
+    /// This operation does not mutate current `Cell` fields,
+    /// instead it calculates new position and returns new `Cell`
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the given `Grid`
+    /// Panics if this operation will violate the given `Grid` left border
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let cell = Cell::new(2, 2);
+    /// let next = cell.strict_left(grid, 2);
+    /// assert_eq!(next, Cell::new(0, 2));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let cell = Cell::new(2, 2);
+    /// let next = cell.strict_left(grid, 3); // panic!
+    /// ```
+    pub fn strict_left(self, grid: Grid, step: u8) -> Cell {
+        self.try_left(grid, step)
+            .unwrap_or_else(|error| panic!("{error}"))
     }
 
     /// Moves current `Cell` to the right by `step` relative to the given `Grid`
@@ -955,15 +1519,8 @@ impl Cell {
     /// let next = cell.strict_right(grid, 3); // panic!
     /// ```
     pub fn strict_right(self, grid: Grid, step: u8) -> Cell {
-        if self.will_overflow_width(grid, step) {
-            panic!(
-                "this operation will violate grid right bounds! cell:{self}, grid:{grid}, step:{step}"
-            );
-        }
-        Cell {
-            global_width: self.global_width + step,
-            global_depth: self.global_depth,
-        }
+        self.try_right(grid, step)
+            .unwrap_or_else(|error| panic!("{error}"))
     }
 
     /// Moves current `Cell` upwards by `step` relative to the given `Grid`
@@ -1262,6 +1819,70 @@ impl Cell {
         )
     }
 
+    /// Moves current `Cell` by signed `width_step` and `depth_step` relative to the given `Grid` in one call
+    ///
+    /// This operation composes the `overflowing` operations, choosing the direction on each axis by the sign of the step:
+    /// positive steps go right and down, negative steps go left and up.
+    /// It returns new `Cell` and two `bool`s, signaling that overflow happened on `width` and `depth` axis respectively
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the given `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let cell = Cell::new(7, 2);
+    /// assert_eq!(cell.overflowing_offset(grid, 2, -1), (Cell::new(9, 1), false, false));
+    /// assert_eq!(cell.overflowing_offset(grid, 5, 0), (Cell::new(2, 2), true, false));
+    ///
+    /// // wraps both axis at once:
+    /// assert_eq!(cell.overflowing_offset(grid, 4, -3), (Cell::new(1, 9), true, true));
+    ///
+    /// // steps beyond `u8` are reduced modulo the grid dimensions:
+    /// assert_eq!(cell.overflowing_offset(grid, 1000, -1000), (Cell::new(7, 2), true, true));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// Cell::new(10, 2).overflowing_offset(grid, 1, 1); // panic!
+    /// ```
+    pub fn overflowing_offset(
+        self,
+        grid: Grid,
+        width_step: i16,
+        depth_step: i16,
+    ) -> (Cell, bool, bool) {
+        let reduce = |step: i16, length: u8| {
+            let magnitude = step.unsigned_abs();
+            match u8::try_from(magnitude) {
+                Ok(magnitude) => (magnitude, false),
+                Err(_) => ((magnitude % length as u16) as u8, true),
+            }
+        };
+        let (width_magnitude, width_reduced) = reduce(width_step, grid.width());
+        let (depth_magnitude, depth_reduced) = reduce(depth_step, grid.depth());
+        let (cell, width_overflowed) = if width_step < 0 {
+            self.overflowing_left(grid, width_magnitude)
+        } else {
+            self.overflowing_right(grid, width_magnitude)
+        };
+        let (cell, depth_overflowed) = if depth_step < 0 {
+            cell.overflowing_up(grid, depth_magnitude)
+        } else {
+            cell.overflowing_down(grid, depth_magnitude)
+        };
+        (
+            cell,
+            width_overflowed || width_reduced,
+            depth_overflowed || depth_reduced,
+        )
+    }
+
     /// Moves current `Cell` upwards by `step` relative to the given `Grid`
     ///
     /// This operation is a wrapper around the `overflowing_up()` method,
@@ -1580,987 +2201,5917 @@ impl Cell {
             || self.global_depth == grid.start.global_depth
             || self.global_depth == grid.end.global_depth
     }
-}
 
-impl fmt::Display for Cell {
-    /// implements display for `Cell`
+    /// Calculates count of steps from the current `Cell` to another on `width` and `depth` axis separately
+    ///
+    /// This operation does not depend on the `Grid`,
+    /// and returns absolute differences of global positions
     ///
     /// # Examples
     ///
     /// ```
     /// use grid_math::Cell;
     ///
-    /// let cell = Cell::new(5, 6);
-    /// assert_eq!(format!("{cell}"), "(5, 6)");
+    /// let cell = Cell::new(5, 5);
+    /// assert_eq!(cell.steps_to(Cell::new(8, 5)), (3, 0));
+    /// assert_eq!(cell.steps_to(Cell::new(5, 1)), (0, 4));
+    /// assert_eq!(cell.steps_to(Cell::new(2, 9)), (3, 4));
+    /// assert_eq!(cell.steps_to(cell), (0, 0));
     /// ```
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "({w}, {d})",
-            w = self.global_width,
-            d = self.global_depth
+    pub fn steps_to(self, other: Cell) -> (u8, u8) {
+        (
+            self.global_width.abs_diff(other.global_width),
+            self.global_depth.abs_diff(other.global_depth),
         )
     }
-}
 
-impl From<(u8, u8)> for Cell {
-    /// implements constructor for `Cell` from (u8, u8)
+    /// Moves current `Cell` by one step towards the center of the given `Grid`
+    ///
+    /// The center of the `Grid` is the `Cell` with `width` = `Grid` width / 2, and `depth` = `Grid` depth / 2.
+    /// The step is made along the axis with the larger distance to the center,
+    /// and along the `width` axis if distances are equal.
+    /// Returns the current `Cell` if it is already at the center
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the given `Grid`
     ///
     /// # Examples
     ///
     /// ```
-    /// use grid_math::Cell;
+    /// use grid_math::{Cell, Grid};
     ///
-    /// let pos = (5, 6);
-    /// let cell = Cell::from(pos);
-    /// assert_eq!((pos.0, pos.1), (cell.global_width(), cell.global_depth()));
+    /// let grid = Grid::new(5, 5); // center is (2, 2)
+    /// assert_eq!(Cell::new(0, 0).step_toward_center(grid), Cell::new(1, 0));
+    /// assert_eq!(Cell::new(4, 0).step_toward_center(grid), Cell::new(3, 0));
+    /// assert_eq!(Cell::new(1, 4).step_toward_center(grid), Cell::new(1, 3));
+    /// assert_eq!(Cell::new(4, 3).step_toward_center(grid), Cell::new(3, 3));
+    /// assert_eq!(Cell::new(2, 2).step_toward_center(grid), Cell::new(2, 2));
     /// ```
-    fn from(value: (u8, u8)) -> Self {
-        Self {
-            global_width: value.0,
-            global_depth: value.1,
+    pub fn step_toward_center(self, grid: Grid) -> Cell {
+        self.within_panic(grid);
+        let center = grid.member(grid.width() / 2, grid.depth() / 2);
+        match self.steps_to(center) {
+            (0, 0) => self,
+            (w, d) if w >= d && self.global_width < center.global_width => {
+                self.strict_right(grid, 1)
+            }
+            (w, d) if w >= d => self.strict_left(grid, 1),
+            _ if self.global_depth < center.global_depth => self.strict_down(grid, 1),
+            _ => self.strict_up(grid, 1),
         }
     }
-}
 
-#[allow(clippy::from_over_into)]
-impl Into<(u8, u8)> for Cell {
-    /// implements conversion from `Cell` into (u8, u8)
+    /// Returns `Direction` of the nearest edge of the given `Grid`
+    ///
+    /// If the `Cell` is equally close to several edges,
+    /// they are prioritized in the following order: `Up`, `Down`, `Left`, `Right`
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the given `Grid`
     ///
     /// # Examples
     ///
     /// ```
-    /// use grid_math::Cell;
+    /// use grid_math::{Cell, Direction, Grid};
     ///
-    /// let cell = Cell::new(5, 6);
-    /// let pos: (u8, u8) = cell.into();
-    /// assert_eq!((pos.0, pos.1), (cell.global_width(), cell.global_depth()));
-    /// ```
-    fn into(self) -> (u8, u8) {
-        (self.global_width, self.global_depth)
+    /// let grid = Grid::new(10, 10);
+    /// assert_eq!(Cell::new(4, 1).nearest_edge(grid), Direction::Up);
+    /// assert_eq!(Cell::new(4, 8).nearest_edge(grid), Direction::Down);
+    /// assert_eq!(Cell::new(1, 4).nearest_edge(grid), Direction::Left);
+    /// assert_eq!(Cell::new(8, 4).nearest_edge(grid), Direction::Right);
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// assert_eq!(Cell::new(2, 2).nearest_edge(grid), Direction::Up); // tie-break
+    /// ```
+    pub fn nearest_edge(self, grid: Grid) -> Direction {
+        [
+            (Direction::Up, self.depth(grid)),
+            (Direction::Down, self.depth_gap(grid)),
+            (Direction::Left, self.width(grid)),
+            (Direction::Right, self.width_gap(grid)),
+        ]
+        .into_iter()
+        .min_by_key(|&(_, gap)| gap)
+        .map(|(direction, _)| direction)
+        .unwrap()
     }
-}
 
-impl Grid {
-    /// Creates new `Grid` with specified `width: u8` and `depth: u8`, starting at (0,0)
+    /// Calculates the `Cell` at the parameter `t` on the straight line from the current `Cell` to another
+    ///
+    /// This operation does not depend on the `Grid`.
+    /// Global positions of the result are rounded to the nearest integer, with halves rounded away from zero
     ///
     /// # Panics
-    /// Panics if `width` or `depth` parameters < 1
+    /// Panics if `t` is not within [0, 1]
     ///
     /// # Examples
     ///
     /// ```
-    /// use grid_math::Grid;
+    /// use grid_math::Cell;
     ///
-    /// let grid = Grid::new(10, 10);
-    /// assert_eq!(format!("{grid}"), "[(0, 0):(9, 9)]");
+    /// let (from, to) = (Cell::new(2, 2), Cell::new(6, 9));
+    /// assert_eq!(from.lerp(to, 0.0), from);
+    /// assert_eq!(from.lerp(to, 1.0), to);
+    /// assert_eq!(from.lerp(to, 0.5), Cell::new(4, 6)); // (4.0, 5.5)
     /// ```
-    pub fn new(width: u8, depth: u8) -> Self {
-        if width < 1 || depth < 1 {
-            panic!("can't create grid with width < 0 or depth < 0!")
+    ///
+    /// ```should_panic
+    /// use grid_math::Cell;
+    ///
+    /// Cell::new(2, 2).lerp(Cell::new(6, 9), 1.5); // panic!
+    /// ```
+    pub fn lerp(self, other: Cell, t: f64) -> Cell {
+        if !(0.0..=1.0).contains(&t) {
+            panic!("lerp parameter is not within [0, 1]! t:{t}")
         }
-        Self {
-            start: Cell {
-                global_width: 0,
-                global_depth: 0,
-            },
-            end: Cell {
-                global_width: width - 1,
-                global_depth: depth - 1,
-            },
+        let lerp = |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * t).round() as u8;
+        Cell {
+            global_width: lerp(self.global_width, other.global_width),
+            global_depth: lerp(self.global_depth, other.global_depth),
         }
     }
 
-    /// Creates new `Grid` with specified `width: u8` and `depth: u8`, starting at indent
+    /// Rotates current `Cell` by 90 degrees clockwise around the given `Grid`
+    ///
+    /// Returned `Cell` is a member of `grid.rotate_90_cw()`, which is the `Grid` with swapped `width` and `depth`
     ///
     /// # Panics
-    /// Panics if `width` or `depth` parameters < 1
+    /// Panics if the `Cell` is not within the given `Grid`
     ///
     /// # Examples
     ///
     /// ```
-    /// use grid_math::{Grid, Cell};
-    ///
-    /// let grid = Grid::indented(5, 5, (2, 2));
-    /// assert_eq!(format!("{grid}"), "[(2, 2):(6, 6)]");
+    /// use grid_math::{Cell, Grid};
     ///
-    /// // use `Cell` as indent:
-    /// let cell = Cell::new(2, 2);
-    /// let grid = Grid::indented(5, 5, cell.into());
-    /// assert_eq!(format!("{grid}"), "[(2, 2):(6, 6)]");
-    /// ```
-    pub fn indented(width: u8, depth: u8, indent: (u8, u8)) -> Self {
-        if width < 1 || depth < 1 {
-            panic!("can't create grid with width < 0 or depth < 0!")
-        }
-        Self {
-            start: Cell {
-                global_width: indent.0,
-                global_depth: indent.1,
-            },
-            end: Cell {
-                global_width: indent.0 + width - 1,
-                global_depth: indent.1 + depth - 1,
-            },
+    /// let grid = Grid::new(3, 3);
+    /// let cell = Cell::new(0, 0).rotate_90_cw(grid);
+    /// assert_eq!(cell, Cell::new(2, 0));
+    /// let cell = cell.rotate_90_cw(grid);
+    /// assert_eq!(cell, Cell::new(2, 2));
+    ///
+    /// let grid = Grid::new(3, 2);
+    /// let cell = Cell::new(2, 0).rotate_90_cw(grid);
+    /// assert_eq!(cell, Cell::new(1, 2));
+    /// assert!(cell.within(grid.rotate_90_cw()));
+    ///
+    /// let (mut current, mut current_grid) = (Cell::new(2, 0), grid);
+    /// for _ in 0..4 {
+    ///     current = current.rotate_90_cw(current_grid);
+    ///     current_grid = current_grid.rotate_90_cw();
+    /// }
+    /// assert_eq!((current, current_grid), (Cell::new(2, 0), grid));
+    /// ```
+    pub fn rotate_90_cw(self, grid: Grid) -> Cell {
+        Cell {
+            global_width: grid.start.global_width + self.depth_gap(grid),
+            global_depth: grid.start.global_depth + self.width(grid),
         }
     }
 
-    /// Checks if the `Grid` is within the another `Grid`
+    /// Rotates current `Cell` by 90 degrees counterclockwise around the given `Grid`
     ///
-    /// # Examples
+    /// Returned `Cell` is a member of `grid.rotate_90_ccw()`, which is the `Grid` with swapped `width` and `depth`
     ///
-    /// ```
-    /// use grid_math::Grid;
+    /// # Panics
+    /// Panics if the `Cell` is not within the given `Grid`
     ///
-    /// let grid = Grid::new(10, 10);
-    /// let subgrid = grid.area(5, 5);
-    /// assert!(subgrid.within(grid));
+    /// # Examples
     ///
-    /// let subgrid = Grid::new(10, 12);
-    /// assert!(!subgrid.within(grid));
     /// ```
-    pub fn within(self, grid: Grid) -> bool {
-        self.start.within(grid) && self.end.within(grid)
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(3, 3);
+    /// let cell = Cell::new(0, 0).rotate_90_ccw(grid);
+    /// assert_eq!(cell, Cell::new(0, 2));
+    ///
+    /// let grid = Grid::indented(4, 2, (1, 1));
+    /// let cell = Cell::new(2, 1);
+    /// let rotated = cell.rotate_90_cw(grid);
+    /// assert_eq!(rotated.rotate_90_ccw(grid.rotate_90_cw()), cell);
+    ///
+    /// let (mut current, mut current_grid) = (cell, grid);
+    /// for _ in 0..4 {
+    ///     current = current.rotate_90_ccw(current_grid);
+    ///     current_grid = current_grid.rotate_90_ccw();
+    /// }
+    /// assert_eq!((current, current_grid), (cell, grid));
+    /// ```
+    pub fn rotate_90_ccw(self, grid: Grid) -> Cell {
+        Cell {
+            global_width: grid.start.global_width + self.depth(grid),
+            global_depth: grid.start.global_depth + self.width_gap(grid),
+        }
     }
 
-    /// Checks if the `Grid` is within the another `Grid`
+    /// Adds `dw` and `dd` to the global positions of the current `Cell`, wrapping around at the `u8` bounds
     ///
-    /// # Panics
-    /// Panics if the `Grid` is not within the another `Grid`
+    /// This operation does not depend on the `Grid`, and treats the whole `u8` space as a torus
     ///
     /// # Examples
     ///
-    /// ```should_panic
-    /// use grid_math::Grid;
+    /// ```
+    /// use grid_math::Cell;
     ///
-    /// let grid = Grid::new(10, 10);
-    /// let subgrid = Grid::new(10, 12);
-    /// subgrid.within_panic(grid);
+    /// let cell = Cell::new(250, 10);
+    /// assert_eq!(cell.wrapping_add_u8(10, 0), Cell::new(4, 10));
+    ///
+    /// let cell = Cell::new(10, 255);
+    /// assert_eq!(cell.wrapping_add_u8(0, 1), Cell::new(10, 0));
+    /// assert_eq!(cell.wrapping_add_u8(5, 5), Cell::new(15, 4));
     /// ```
-    pub fn within_panic(self, grid: Grid) {
-        if !self.within(grid) {
-            panic!("subgrid is not within given grid! subgrid:{self}, grid:{grid}")
+    pub fn wrapping_add_u8(self, dw: u8, dd: u8) -> Cell {
+        Cell {
+            global_width: self.global_width.wrapping_add(dw),
+            global_depth: self.global_depth.wrapping_add(dd),
         }
     }
 
-    /// Returns new `Cell` by `width: u8` and `depth: u8` relative to the current `Grid`
+    /// Projects current `Cell` onto the nearest side of the given `Grid`,
+    /// executing corresponding project operation
+    ///
+    /// The nearest side is chosen with the `nearest_edge` method,
+    /// so ties are broken in the following order: `Up`, `Down`, `Left`, `Right`
     ///
     /// # Panics
-    /// Panics if `width` or `depth` of the requested member exceeds borders of the current `Grid`
+    /// Panics if the `Cell` is not within the given `Grid`
     ///
     /// # Examples
     ///
     /// ```
-    /// use grid_math::{Grid, Cell};
+    /// use grid_math::{Cell, Grid};
     ///
-    /// let grid = Grid::indented(5, 5, (2, 2)); // 5x5 grid, starting at (2,2)
-    /// let member = grid.member(4, 4);
-    /// assert_eq!(member, Cell::new(6, 6));
-    /// ```
-    pub fn member(self, width: u8, depth: u8) -> Cell {
-        self.start
-            .strict_right(self, width)
-            .strict_down(self, depth)
+    /// let grid = Grid::new(10, 10);
+    /// assert_eq!(Cell::new(4, 1).project_onto_nearest_edge(grid), Cell::new(4, 0));
+    /// assert_eq!(Cell::new(4, 8).project_onto_nearest_edge(grid), Cell::new(4, 9));
+    /// assert_eq!(Cell::new(1, 4).project_onto_nearest_edge(grid), Cell::new(0, 4));
+    /// assert_eq!(Cell::new(8, 4).project_onto_nearest_edge(grid), Cell::new(9, 4));
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// assert_eq!(Cell::new(2, 2).project_onto_nearest_edge(grid), Cell::new(2, 0)); // tie-break
+    /// ```
+    pub fn project_onto_nearest_edge(self, grid: Grid) -> Cell {
+        match self.nearest_edge(grid) {
+            Direction::Up => self.project_up(grid),
+            Direction::Down => self.project_down(grid),
+            Direction::Left => self.project_left(grid),
+            Direction::Right => self.project_right(grid),
+        }
     }
 
-    /// Returns new `Grid` with `width: u8` and `depth: u8`, which is a subgrid
-    /// of current `Grid`, starting at current `Grid` start
+    /// Calculates Manhattan distance between the current `Cell` and another,
+    /// which is the sum of steps on `width` and `depth` axis
     ///
-    /// # Panics
-    /// Panics if `width` or `depth` parameters < 1
-    /// Panics if `width` or `depth` of the requested area exceeds borders of the current `Grid`
+    /// This operation does not depend on the `Grid`
     ///
     /// # Examples
     ///
     /// ```
-    /// use grid_math::{Grid, Cell};
+    /// use grid_math::Cell;
     ///
-    /// let grid = Grid::indented(5, 5, (2, 2)); // 5x5 grid, starting at (2,2)
-    /// let area = grid.area(3, 3);
-    /// assert_eq!(format!("{area}"), "[(2, 2):(4, 4)]");
-    /// ```
-    pub fn area(self, width: u8, depth: u8) -> Grid {
-        if width < 1 || depth < 1 {
-            panic!("can't create grid with width < 0 or depth < 0!")
-        }
-        Grid {
-            start: self.start,
-            end: self
-                .start
-                .strict_right(self, width - 1)
-                .strict_down(self, depth - 1),
-        }
+    /// let cell = Cell::new(2, 3);
+    /// assert_eq!(cell.manhattan_distance(Cell::new(5, 1)), 5);
+    /// assert_eq!(Cell::new(5, 1).manhattan_distance(cell), 5);
+    /// assert_eq!(cell.manhattan_distance(cell), 0);
+    /// assert_eq!(Cell::new(0, 0).manhattan_distance(Cell::new(255, 255)), 510);
+    /// ```
+    pub fn manhattan_distance(self, other: Cell) -> u16 {
+        let (width_steps, depth_steps) = self.steps_to(other);
+        width_steps as u16 + depth_steps as u16
     }
 
-    /// Returns new `Grid` with `width: u8` and `depth: u8`, which is a subgrid
-    /// of current `Grid`, starting at current `Grid` start + indent
+    /// Calculates Chebyshev distance between the current `Cell` and another,
+    /// which is the max of steps on `width` and `depth` axis
     ///
-    /// # Panics
-    /// Panics if `width` or `depth` parameters < 1
-    /// Panics if `width` or `depth` of the requested slice exceeds borders of the current `Grid`
-    /// Panics if `indent` of the requested slice exceeds borders of the current `Grid`
+    /// This operation does not depend on the `Grid`
     ///
     /// # Examples
     ///
     /// ```
-    /// use grid_math::{Grid, Cell};
-    ///
-    /// let grid = Grid::new(10, 10);
-    /// let slice = grid.slice(3, 3, (2, 2));
-    /// assert_eq!(format!("{slice}"), "[(2, 2):(4, 4)]");
+    /// use grid_math::Cell;
     ///
-    /// // use `Cell` as indent:
-    /// let cell = Cell::new(2, 2);
-    /// let slice = grid.slice(3, 3, cell.into());
-    /// assert_eq!(format!("{slice}"), "[(2, 2):(4, 4)]");
-    /// ```
-    pub fn slice(self, width: u8, depth: u8, indent: (u8, u8)) -> Grid {
-        if width < 1 || depth < 1 {
-            panic!("can't create grid with width < 0 or depth < 0!")
-        }
-        Grid {
-            start: self
-                .start
-                .strict_right(self, indent.0)
-                .strict_down(self, indent.1),
-            end: self
-                .start
-                .strict_right(self, indent.0 + width - 1)
-                .strict_down(self, indent.1 + depth - 1),
-        }
+    /// let cell = Cell::new(2, 3);
+    /// assert_eq!(cell.chebyshev_distance(Cell::new(5, 1)), 3);
+    /// assert_eq!(Cell::new(5, 1).chebyshev_distance(cell), 3);
+    /// assert_eq!(cell.chebyshev_distance(cell), 0);
+    /// assert_eq!(Cell::new(0, 0).chebyshev_distance(Cell::new(255, 255)), 255);
+    /// ```
+    pub fn chebyshev_distance(self, other: Cell) -> u8 {
+        let (width_steps, depth_steps) = self.steps_to(other);
+        width_steps.max(depth_steps)
     }
 
-    /// Returns `start` cell of `Grid`
+    /// Calculates distance between the current `Cell` and another, using the given `DistanceMetric`
+    ///
+    /// This operation does not depend on the `Grid`
     ///
     /// # Examples
     ///
     /// ```
-    /// use grid_math::{Grid, Cell};
+    /// use grid_math::{Cell, DistanceMetric};
     ///
-    /// let grid = Grid::new(10, 10);
-    /// let start = grid.start();
-    /// assert_eq!(start, Cell::new(0, 0));
+    /// let cell = Cell::new(2, 3);
+    /// assert_eq!(cell.distance(Cell::new(5, 1), DistanceMetric::Manhattan), 5);
+    /// assert_eq!(cell.distance(Cell::new(5, 1), DistanceMetric::Chebyshev), 3);
     /// ```
-    pub fn start(self) -> Cell {
-        self.start
+    pub fn distance(self, other: Cell, metric: DistanceMetric) -> u16 {
+        match metric {
+            DistanceMetric::Manhattan => self.manhattan_distance(other),
+            DistanceMetric::Chebyshev => self.chebyshev_distance(other) as u16,
+        }
     }
 
-    /// Returns `end` cell of `Grid`
+    /// Returns orthogonal neighbors of the current `Cell`, that are within the given `Grid`
+    ///
+    /// Neighbors go in the following order: `up`, `down`, `left`, `right`.
+    /// Neighbors that fall off the `Grid` are omitted, and the current `Cell` is never included
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the given `Grid`
     ///
     /// # Examples
     ///
     /// ```
-    /// use grid_math::{Grid, Cell};
+    /// use grid_math::{Cell, Grid};
     ///
     /// let grid = Grid::new(10, 10);
-    /// let end = grid.end();
-    /// assert_eq!(end, Cell::new(9, 9));
+    /// let neighbors = Cell::new(5, 5).neighbors(grid);
+    /// assert_eq!(neighbors, vec![Cell::new(5, 4), Cell::new(5, 6), Cell::new(4, 5), Cell::new(6, 5)]);
+    ///
+    /// let neighbors = grid.start().neighbors(grid);
+    /// assert_eq!(neighbors, vec![Cell::new(0, 1), Cell::new(1, 0)]);
     /// ```
-    pub fn end(self) -> Cell {
-        self.end
+    pub fn neighbors(self, grid: Grid) -> Vec<Cell> {
+        self.within_panic(grid);
+        Heading::ALL[..4]
+            .iter()
+            .filter_map(|heading| self.checked_offset(grid, heading.offset()))
+            .collect()
     }
 
-    /// Calculates `width` of `Grid`
+    /// Returns orthogonal and diagonal neighbors of the current `Cell`, that are within the given `Grid`
+    ///
+    /// Neighbors go in the following order: `up`, `down`, `left`, `right`,
+    /// `up-left`, `up-right`, `down-left`, `down-right`.
+    /// Neighbors that fall off the `Grid` are omitted, and the current `Cell` is never included
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the given `Grid`
     ///
     /// # Examples
     ///
     /// ```
-    /// use grid_math::Grid;
+    /// use grid_math::{Cell, Grid};
     ///
     /// let grid = Grid::new(10, 10);
-    /// let width = grid.width();
-    /// assert_eq!(width, 10);
+    /// let neighbors = Cell::new(5, 5).neighbors_diagonal(grid);
+    /// assert_eq!(neighbors.len(), 8);
+    /// assert!(!neighbors.contains(&Cell::new(5, 5)));
+    ///
+    /// let neighbors = grid.end().neighbors_diagonal(grid);
+    /// assert_eq!(neighbors, vec![Cell::new(9, 8), Cell::new(8, 9), Cell::new(8, 8)]);
     /// ```
-    pub fn width(self) -> u8 {
-        self.end.global_width - self.start.global_width + 1
+    pub fn neighbors_diagonal(self, grid: Grid) -> Vec<Cell> {
+        self.within_panic(grid);
+        Heading::ALL
+            .iter()
+            .filter_map(|heading| self.checked_offset(grid, heading.offset()))
+            .collect()
     }
 
-    /// Calculates `depth` of `Grid`
+    /// Moves current `Cell` by the signed `offset` within the given `Grid`,
+    /// returns `None` if the `Cell` falls off the `Grid`
+    fn checked_offset(self, grid: Grid, offset: (i8, i8)) -> Option<Cell> {
+        match self.overflowing_offset(grid, offset.0 as i16, offset.1 as i16) {
+            (cell, false, false) => Some(cell),
+            _ => None,
+        }
+    }
+
+    /// Reflects current `Cell` across the center of the `space_width` x `space_depth` space, starting at (0, 0)
+    ///
+    /// This operation does not depend on the `Grid`,
+    /// global position `w` maps to `space_width - 1 - w`, and `d` maps to `space_depth - 1 - d`
+    ///
+    /// # Panics
+    /// Panics if `space_width` or `space_depth` parameters < 1
+    /// Panics if the `Cell` is not within the space
     ///
     /// # Examples
     ///
     /// ```
-    /// use grid_math::Grid;
+    /// use grid_math::Cell;
     ///
-    /// let grid = Grid::new(10, 10);
-    /// let depth = grid.depth();
-    /// assert_eq!(depth, 10);
+    /// let cell = Cell::new(1, 6);
+    /// assert_eq!(cell.mirror_in_space(10, 8), Cell::new(8, 1));
+    /// assert_eq!(cell.mirror_in_space(10, 8).mirror_in_space(10, 8), cell);
+    ///
+    /// // center of the odd dimension is fixed:
+    /// let cell = Cell::new(2, 2);
+    /// assert_eq!(cell.mirror_in_space(5, 5), cell);
     /// ```
-    pub fn depth(self) -> u8 {
-        self.end.global_depth - self.start.global_depth + 1
+    ///
+    /// ```should_panic
+    /// use grid_math::Cell;
+    ///
+    /// let cell = Cell::new(10, 6);
+    /// cell.mirror_in_space(10, 8); // panic!
+    /// ```
+    pub fn mirror_in_space(self, space_width: u8, space_depth: u8) -> Cell {
+        self.within_panic(Grid::new(space_width, space_depth));
+        Cell {
+            global_width: space_width - 1 - self.global_width,
+            global_depth: space_depth - 1 - self.global_depth,
+        }
     }
 
-    /// Calculates `size: u16` of `Grid`
+    /// Returns knight's move destinations of the current `Cell`, that are within the given `Grid`
+    ///
+    /// Knight's move is an L-shaped move by 2 on one axis and by 1 on the other.
+    /// Moves go in the following order: `up-up-left`, `up-up-right`, `down-down-left`, `down-down-right`,
+    /// `left-left-up`, `left-left-down`, `right-right-up`, `right-right-down`.
+    /// Moves that fall off the `Grid` are omitted
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the given `Grid`
     ///
     /// # Examples
     ///
     /// ```
-    /// use grid_math::Grid;
+    /// use grid_math::{Cell, Grid};
     ///
-    /// let grid = Grid::new(10, 10);
-    /// let size = grid.size();
-    /// assert_eq!(size, 100);
-    /// ```
-    pub fn size(self) -> u16 {
-        self.width() as u16 * self.depth() as u16
+    /// let grid = Grid::new(8, 8);
+    /// assert_eq!(Cell::new(4, 4).knight_moves(grid).len(), 8);
+    /// assert_eq!(grid.start().knight_moves(grid), vec![Cell::new(1, 2), Cell::new(2, 1)]);
+    ///
+    /// let grid = Grid::new(3, 3);
+    /// assert_eq!(grid.member(1, 1).knight_moves(grid), vec![]);
+    /// assert_eq!(grid.member(0, 1).knight_moves(grid), vec![Cell::new(2, 0), Cell::new(2, 2)]);
+    /// ```
+    pub fn knight_moves(self, grid: Grid) -> Vec<Cell> {
+        const KNIGHT_OFFSETS: [(i8, i8); 8] = [
+            (-1, -2),
+            (1, -2),
+            (-1, 2),
+            (1, 2),
+            (-2, -1),
+            (-2, 1),
+            (2, -1),
+            (2, 1),
+        ];
+        self.within_panic(grid);
+        KNIGHT_OFFSETS
+            .into_iter()
+            .filter_map(|offset| self.checked_offset(grid, offset))
+            .collect()
     }
 
-    /// Returns `Cells`, which is an iterator over every cell of the `Grid`
+    /// Samples `steps + 1` `Cell`s evenly along the straight line from the current `Cell` to another,
+    /// including both ends
+    ///
+    /// This operation does not depend on the `Grid`, every sample is calculated with `lerp`
+    ///
+    /// # Panics
+    /// Panics if `steps` parameter < 1
     ///
     /// # Examples
     ///
-    /// Get every `Cell` on `width` and `depth` axis:
     /// ```
-    /// use grid_math::{Cell, Grid};
-    ///
-    /// let grid = Grid::new(3, 3);
+    /// use grid_math::Cell;
     ///
-    /// let axis_cells: Vec<Cell> = grid
-    ///     .cells()
-    ///     .filter(|cell| {
-    ///         cell.global_width() == grid.start().global_width() || cell.global_depth() == grid.start().global_depth()
-    ///     })
-    ///     .collect();
-    /// assert_eq!(axis_cells, vec![
-    ///     Cell::new(0, 0),
-    ///     Cell::new(1, 0),
-    ///     Cell::new(2, 0),
-    ///     Cell::new(0, 1),
-    ///     Cell::new(0, 2),
+    /// let (from, to) = (Cell::new(2, 2), Cell::new(6, 9));
+    /// assert_eq!(from.interpolate_path(to, 1), vec![from, to]);
+    /// assert_eq!(from.interpolate_path(to, 4), vec![
+    ///     Cell::new(2, 2),
+    ///     Cell::new(3, 4), // (3.0, 3.75)
+    ///     Cell::new(4, 6), // (4.0, 5.5)
+    ///     Cell::new(5, 7), // (5.0, 7.25)
+    ///     Cell::new(6, 9),
     /// ]);
     /// ```
-    pub fn cells(self) -> Cells {
-        Cells::from(self)
+    ///
+    /// ```should_panic
+    /// use grid_math::Cell;
+    ///
+    /// Cell::new(2, 2).interpolate_path(Cell::new(6, 9), 0); // panic!
+    /// ```
+    pub fn interpolate_path(self, other: Cell, steps: u8) -> Vec<Cell> {
+        if steps < 1 {
+            panic!("can't interpolate path with steps < 1")
+        }
+        (0..=steps)
+            .map(|step| self.lerp(other, step as f64 / steps as f64))
+            .collect()
     }
 
-    /// Returns `Rows`, which is an iterator over every row of the `Grid`
+    /// Swaps `global_width` and `global_depth` of the current `Cell`,
+    /// mirroring it across the main diagonal of the global space
     ///
     /// # Examples
     ///
-    /// Print out `Grid` in custom format:
     /// ```
-    /// use grid_math::{Cell, Grid};
-    ///
-    /// let grid = Grid::new(3, 3);
-    /// let grid_string = grid
-    ///     .rows()
-    ///     .map(|row| {
-    ///         row.cells().map(|_| " [#]")
-    ///             .chain(std::iter::once("\n\n"))
-    ///             .collect::<String>()
-    ///     })
-    ///     .collect::<String>();
-    /// assert_eq!(grid_string,
-    /// " \
-    ///  [#] [#] [#]
-    ///
-    ///  [#] [#] [#]
-    ///
-    ///  [#] [#] [#]
+    /// use grid_math::Cell;
     ///
-    /// "
-    /// );
+    /// let cell = Cell::new(2, 7);
+    /// assert_eq!(cell.transpose(), Cell::new(7, 2));
+    /// assert_eq!(cell.transpose().transpose(), cell);
     /// ```
-    pub fn rows(self) -> Rows {
-        Rows::from(self)
+    pub fn transpose(self) -> Cell {
+        Cell {
+            global_width: self.global_depth,
+            global_depth: self.global_width,
+        }
     }
 
-    /// Returns `Columns`, which is an iterator over every column of the `Grid`
+    /// Returns `LineIter`, which is an iterator over every `Cell` on the straight line
+    /// from the current `Cell` to another inclusive, calculated with Bresenham's line algorithm
+    ///
+    /// This operation does not depend on the `Grid`
     ///
     /// # Examples
     ///
-    /// Get every `Cell` on the first column of `Grid`:
     /// ```
-    /// use grid_math::{Cell, Grid};
-    ///
-    /// let grid = Grid::new(3, 3);
+    /// use grid_math::Cell;
     ///
-    /// let first_column_cells: Vec<Cell> = grid
-    ///     .columns()
-    ///     .next()
-    ///     .unwrap()
-    ///     .cells()
-    ///     .collect();
+    /// let line: Vec<Cell> = Cell::new(3, 3).line_to(Cell::new(0, 0)).collect();
+    /// assert_eq!(line, vec![Cell::new(3, 3), Cell::new(2, 2), Cell::new(1, 1), Cell::new(0, 0)]);
     ///
-    /// assert_eq!(first_column_cells, vec![
-    ///     Cell::new(0, 0),
+    /// let line: Vec<Cell> = Cell::new(0, 1).line_to(Cell::new(6, 0)).collect();
+    /// assert_eq!(line, vec![
     ///     Cell::new(0, 1),
-    ///     Cell::new(0, 2),
+    ///     Cell::new(1, 1),
+    ///     Cell::new(2, 1),
+    ///     Cell::new(3, 0),
+    ///     Cell::new(4, 0),
+    ///     Cell::new(5, 0),
+    ///     Cell::new(6, 0),
     /// ]);
-    /// ```
-    pub fn columns(self) -> Columns {
-        Columns::from(self)
+    ///
+    /// let line: Vec<Cell> = Cell::new(2, 5).line_to(Cell::new(2, 2)).collect();
+    /// assert_eq!(line, vec![Cell::new(2, 5), Cell::new(2, 4), Cell::new(2, 3), Cell::new(2, 2)]);
+    ///
+    /// assert_eq!(Cell::new(7, 7).line_to(Cell::new(7, 7)).count(), 1);
+    /// ```
+    pub fn line_to(self, other: Cell) -> LineIter {
+        let width_delta = (other.global_width as i16 - self.global_width as i16).abs();
+        let depth_delta = -(other.global_depth as i16 - self.global_depth as i16).abs();
+        LineIter {
+            current: self,
+            end: other,
+            width_delta,
+            depth_delta,
+            width_step: if self.global_width < other.global_width {
+                1
+            } else {
+                -1
+            },
+            depth_step: if self.global_depth < other.global_depth {
+                1
+            } else {
+                -1
+            },
+            error: width_delta + depth_delta,
+            consumed: false,
+        }
     }
-}
 
-impl From<(Cell, Cell)> for Grid {
-    /// implements constructor for `Grid` from (Cell, Cell)
+    /// Returns an iterator over every `Cell` on the straight line from the current `Cell` to another,
+    /// same as `line_to`, but stops at the first `Cell`, that is not within the given `Grid`
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the given `Grid`
     ///
     /// # Examples
     ///
     /// ```
     /// use grid_math::{Cell, Grid};
     ///
-    /// let cells = (Cell::new(2, 2), Cell::new(5, 5));
-    /// let grid = Grid::from(cells);
-    /// assert_eq!((cells.0, cells.1), (grid.start(), grid.end()));
+    /// let grid = Grid::new(4, 4);
+    /// let line: Vec<Cell> = Cell::new(1, 0).line_to_within(Cell::new(7, 3), grid).collect();
+    /// assert_eq!(line, vec![Cell::new(1, 0), Cell::new(2, 1), Cell::new(3, 1)]);
+    /// ```
+    pub fn line_to_within(
+        self,
+        other: Cell,
+        grid: Grid,
+    ) -> TakeWhile<LineIter, impl FnMut(&Cell) -> bool> {
+        self.within_panic(grid);
+        self.line_to(other)
+            .take_while(move |&cell| grid.contains_cell(cell))
+    }
+
+    /// Checks if three `Cell`s lie on a common straight line
+    ///
+    /// This operation does not depend on the `Grid`, and works for lines of any orientation
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Cell;
+    ///
+    /// assert!(Cell::collinear(Cell::new(1, 3), Cell::new(4, 3), Cell::new(9, 3)));
+    /// assert!(Cell::collinear(Cell::new(2, 0), Cell::new(2, 7), Cell::new(2, 4)));
+    /// assert!(Cell::collinear(Cell::new(0, 0), Cell::new(2, 2), Cell::new(255, 255)));
+    /// assert!(Cell::collinear(Cell::new(0, 0), Cell::new(2, 1), Cell::new(6, 3)));
+    /// assert!(!Cell::collinear(Cell::new(0, 0), Cell::new(2, 1), Cell::new(5, 3)));
+    /// ```
+    pub fn collinear(a: Cell, b: Cell, c: Cell) -> bool {
+        let delta = |from: Cell, to: Cell| {
+            (
+                to.global_width as i32 - from.global_width as i32,
+                to.global_depth as i32 - from.global_depth as i32,
+            )
+        };
+        let ((w1, d1), (w2, d2)) = (delta(a, b), delta(a, c));
+        w1 * d2 - d1 * w2 == 0
+    }
+
+    /// Returns `Cell`s of the triangular cone, extending from the current `Cell` in the given `Heading`,
+    /// that are within the given `Grid`
+    ///
+    /// The cone widens by one `Cell` on each side for every step of `length`, covering 90 degrees.
+    /// Cells go layer by layer from the nearest one, and in `Grid` order within the layer.
+    /// The current `Cell` is never included
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the given `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid, Heading};
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let cone = Cell::new(5, 4).cone(grid, Heading::Right, 2);
+    /// assert_eq!(cone, vec![
+    ///     Cell::new(6, 3),
+    ///     Cell::new(6, 4),
+    ///     Cell::new(6, 5),
+    ///     Cell::new(7, 2),
+    ///     Cell::new(7, 3),
+    ///     Cell::new(7, 4),
+    ///     Cell::new(7, 5),
+    ///     Cell::new(7, 6),
+    /// ]);
+    ///
+    /// let cone = Cell::new(7, 1).cone(grid, Heading::Right, 3); // clipped at grid edges
+    /// assert_eq!(cone, vec![
+    ///     Cell::new(8, 0),
+    ///     Cell::new(8, 1),
+    ///     Cell::new(8, 2),
+    ///     Cell::new(9, 0),
+    ///     Cell::new(9, 1),
+    ///     Cell::new(9, 2),
+    ///     Cell::new(9, 3),
+    /// ]);
+    ///
+    /// let cone = Cell::new(0, 0).cone(grid, Heading::DownRight, 1);
+    /// assert_eq!(cone, vec![Cell::new(1, 0), Cell::new(0, 1), Cell::new(1, 1)]);
+    /// ```
+    pub fn cone(self, grid: Grid, heading: Heading, length: u8) -> Vec<Cell> {
+        self.within_panic(grid);
+        let (width_sign, depth_sign) = heading.offset();
+        let (width_sign, depth_sign) = (width_sign as i16, depth_sign as i16);
+        let mut cells = Vec::new();
+        for layer in 1..=length as i16 {
+            for depth_offset in -layer..=layer {
+                for width_offset in -layer..=layer {
+                    let in_layer = match (width_sign, depth_sign) {
+                        (0, _) => depth_offset == layer * depth_sign,
+                        (_, 0) => width_offset == layer * width_sign,
+                        _ => {
+                            width_offset.abs().max(depth_offset.abs()) == layer
+                                && width_offset * width_sign >= 0
+                                && depth_offset * depth_sign >= 0
+                        }
+                    };
+                    let global_width = self.global_width as i16 + width_offset;
+                    let global_depth = self.global_depth as i16 + depth_offset;
+                    if in_layer
+                        && (grid.start.global_width as i16..=grid.end.global_width as i16)
+                            .contains(&global_width)
+                        && (grid.start.global_depth as i16..=grid.end.global_depth as i16)
+                            .contains(&global_depth)
+                    {
+                        cells.push(Cell {
+                            global_width: global_width as u8,
+                            global_depth: global_depth as u8,
+                        });
+                    }
+                }
+            }
+        }
+        cells
+    }
+
+    /// Returns the `Heading` from the current `Cell` to another one in global space,
+    /// or `None` if they are the same `Cell`
+    ///
+    /// Diagonal `Heading` is chosen when both axes differ
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Heading};
+    ///
+    /// let cell = Cell::new(5, 5);
+    /// assert_eq!(cell.direction_to(Cell::new(5, 1)), Some(Heading::Up));
+    /// assert_eq!(cell.direction_to(Cell::new(9, 5)), Some(Heading::Right));
+    /// assert_eq!(cell.direction_to(Cell::new(9, 6)), Some(Heading::DownRight));
+    /// assert_eq!(cell.direction_to(Cell::new(0, 4)), Some(Heading::UpLeft));
+    /// assert_eq!(cell.direction_to(cell), None);
+    /// ```
+    pub fn direction_to(self, other: Cell) -> Option<Heading> {
+        let width_sign = other.global_width.cmp(&self.global_width);
+        let depth_sign = other.global_depth.cmp(&self.global_depth);
+        let heading = match (width_sign, depth_sign) {
+            (Ordering::Equal, Ordering::Less) => Heading::Up,
+            (Ordering::Equal, Ordering::Greater) => Heading::Down,
+            (Ordering::Less, Ordering::Equal) => Heading::Left,
+            (Ordering::Greater, Ordering::Equal) => Heading::Right,
+            (Ordering::Less, Ordering::Less) => Heading::UpLeft,
+            (Ordering::Greater, Ordering::Less) => Heading::UpRight,
+            (Ordering::Less, Ordering::Greater) => Heading::DownLeft,
+            (Ordering::Greater, Ordering::Greater) => Heading::DownRight,
+            (Ordering::Equal, Ordering::Equal) => return None,
+        };
+        Some(heading)
+    }
+
+    /// Moves current `Cell` by one step in the `Heading` to another `Cell`,
+    /// saturating at the bounds of the given `Grid`
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the given `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let target = Cell::new(7, 2);
+    /// let mut cell = Cell::new(1, 6);
+    /// assert_eq!(cell.step_toward(target, grid), Cell::new(2, 5));
+    ///
+    /// let mut steps = 0;
+    /// while cell != target {
+    ///     cell = cell.step_toward(target, grid);
+    ///     steps += 1;
+    /// }
+    /// assert_eq!(steps, 6);
+    /// assert_eq!(cell.step_toward(target, grid), target);
+    ///
+    /// // target outside of the grid is approached until the bounds:
+    /// let cell = Cell::new(9, 9).step_toward(Cell::new(20, 9), grid);
+    /// assert_eq!(cell, Cell::new(9, 9));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// Cell::new(10, 0).step_toward(Cell::new(0, 0), grid); // panic!
+    /// ```
+    pub fn step_toward(self, other: Cell, grid: Grid) -> Cell {
+        self.within_panic(grid);
+        match self.direction_to(other) {
+            Some(Heading::Up) => self.saturating_up(grid, 1),
+            Some(Heading::Down) => self.saturating_down(grid, 1),
+            Some(Heading::Left) => self.saturating_left(grid, 1),
+            Some(Heading::Right) => self.saturating_right(grid, 1),
+            Some(Heading::UpLeft) => self.saturating_up(grid, 1).saturating_left(grid, 1),
+            Some(Heading::UpRight) => self.saturating_up(grid, 1).saturating_right(grid, 1),
+            Some(Heading::DownLeft) => self.saturating_down(grid, 1).saturating_left(grid, 1),
+            Some(Heading::DownRight) => self.saturating_down(grid, 1).saturating_right(grid, 1),
+            None => self,
+        }
+    }
+
+    /// Returns current `Cell` unchanged for player one, and rotated by 180 degrees around the given `Grid`
+    /// for player two, so each player sees the board from their own side
+    ///
+    /// # Note
+    /// Rotation by 180 degrees keeps the `Grid` dimensions, so non-square `Grid`s are handled the same way:
+    /// the `Cell` is mirrored on both axes within the `Grid`
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the given `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::indented(8, 8, (1, 1));
+    /// let cell = Cell::new(2, 7);
+    /// assert_eq!(cell.flip_for_player(grid, false), cell);
+    /// assert_eq!(cell.flip_for_player(grid, true), Cell::new(7, 2));
+    /// assert_eq!(cell.flip_for_player(grid, true).flip_for_player(grid, true), cell);
+    ///
+    /// let grid = Grid::new(5, 3);
+    /// assert_eq!(Cell::new(1, 0).flip_for_player(grid, true), Cell::new(3, 2));
+    /// ```
+    pub fn flip_for_player(self, grid: Grid, player_two: bool) -> Cell {
+        self.within_panic(grid);
+        if !player_two {
+            return self;
+        }
+        Cell {
+            global_width: grid.start.global_width + self.width_gap(grid),
+            global_depth: grid.start.global_depth + self.depth_gap(grid),
+        }
+    }
+
+    /// Reflects current `Cell` across the vertical center line of the given `Grid`,
+    /// so the relative `width` index `w` becomes `grid.width() - 1 - w`
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the given `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::indented(5, 3, (2, 1));
+    /// let cell = Cell::new(3, 2);
+    /// assert_eq!(cell.flip_horizontal(grid), Cell::new(5, 2));
+    /// assert_eq!(cell.flip_horizontal(grid).flip_horizontal(grid), cell);
+    ///
+    /// // center column of the odd-width grid is fixed:
+    /// let center = Cell::new(4, 3);
+    /// assert_eq!(center.flip_horizontal(grid), center);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(5, 3);
+    /// Cell::new(5, 0).flip_horizontal(grid); // panic!
+    /// ```
+    pub fn flip_horizontal(self, grid: Grid) -> Cell {
+        self.within_panic(grid);
+        Cell {
+            global_width: grid.start.global_width + self.width_gap(grid),
+            global_depth: self.global_depth,
+        }
+    }
+
+    /// Reflects current `Cell` across the horizontal center line of the given `Grid`,
+    /// so the relative `depth` index `d` becomes `grid.depth() - 1 - d`
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the given `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::indented(3, 5, (1, 2));
+    /// let cell = Cell::new(2, 3);
+    /// assert_eq!(cell.flip_vertical(grid), Cell::new(2, 5));
+    /// assert_eq!(cell.flip_vertical(grid).flip_vertical(grid), cell);
+    ///
+    /// // center row of the odd-depth grid is fixed:
+    /// let center = Cell::new(1, 4);
+    /// assert_eq!(center.flip_vertical(grid), center);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(3, 5);
+    /// Cell::new(0, 5).flip_vertical(grid); // panic!
+    /// ```
+    pub fn flip_vertical(self, grid: Grid) -> Cell {
+        self.within_panic(grid);
+        Cell {
+            global_width: self.global_width,
+            global_depth: grid.start.global_depth + self.depth_gap(grid),
+        }
+    }
+
+    /// Returns the nearest member of the given `Grid` to the current `Cell`
+    ///
+    /// Mirrors `Grid::clamp`: global positions are clamped into the `Grid` bounds on each axis independently.
+    /// Unlike `project_*` methods, this operation does not panic if the `Cell` is not within the `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::indented(5, 5, (2, 2));
+    /// assert_eq!(Cell::new(0, 1).clamp(grid), Cell::new(2, 2));
+    /// assert_eq!(Cell::new(9, 200).clamp(grid), Cell::new(6, 6));
+    /// assert_eq!(Cell::new(4, 0).clamp(grid), Cell::new(4, 2));
+    /// assert_eq!(Cell::new(3, 5).clamp(grid), Cell::new(3, 5));
+    /// ```
+    pub fn clamp(self, grid: Grid) -> Cell {
+        grid.clamp(self)
+    }
+
+    /// Encodes current `Cell` into the fixed-size binary representation: `[global_width, global_depth]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Cell;
+    ///
+    /// let cell = Cell::new(3, 250);
+    /// assert_eq!(cell.to_bytes(), [3, 250]);
+    /// assert_eq!(Cell::from_bytes(cell.to_bytes()), cell);
+    /// ```
+    pub const fn to_bytes(self) -> [u8; 2] {
+        [self.global_width, self.global_depth]
+    }
+
+    /// Decodes `Cell` from the fixed-size binary representation: `[global_width, global_depth]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Cell;
+    ///
+    /// const CELL: Cell = Cell::from_bytes([7, 1]);
+    /// assert_eq!(CELL, Cell::new(7, 1));
+    /// assert_eq!(Cell::from_bytes(CELL.to_bytes()), CELL);
+    /// ```
+    pub const fn from_bytes(bytes: [u8; 2]) -> Cell {
+        Cell {
+            global_width: bytes[0],
+            global_depth: bytes[1],
+        }
+    }
+
+    /// Returns the midpoint between the current `Cell` and another one in global space
+    ///
+    /// # Note
+    /// The average of global positions is rounded down on each axis,
+    /// so the midpoint of adjacent `Cell`s is biased toward the lower coordinate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Cell;
+    ///
+    /// assert_eq!(Cell::new(2, 4).midpoint(Cell::new(6, 10)), Cell::new(4, 7));
+    /// assert_eq!(Cell::new(6, 10).midpoint(Cell::new(2, 4)), Cell::new(4, 7));
+    /// assert_eq!(Cell::new(255, 255).midpoint(Cell::new(253, 0)), Cell::new(254, 127));
+    ///
+    /// // adjacent cells are biased toward the lower coordinate:
+    /// assert_eq!(Cell::new(3, 3).midpoint(Cell::new(4, 3)), Cell::new(3, 3));
+    /// assert_eq!(Cell::new(4, 4).midpoint(Cell::new(3, 3)), Cell::new(3, 3));
+    /// ```
+    pub fn midpoint(self, other: Cell) -> Cell {
+        Cell {
+            global_width: self.global_width.midpoint(other.global_width),
+            global_depth: self.global_depth.midpoint(other.global_depth),
+        }
+    }
+
+    /// Returns a copy of the current `Cell` with `global_width` replaced by the given one
+    ///
+    /// This is a pure operation on global position, so no bounds are checked
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Cell;
+    ///
+    /// let cell = Cell::new(7, 2);
+    /// assert_eq!(cell.with_width(0), Cell::new(0, 2));
+    /// assert_eq!(cell.with_width(3).with_depth(4), Cell::new(3, 4));
+    /// ```
+    pub const fn with_width(self, global_width: u8) -> Cell {
+        Cell {
+            global_width,
+            global_depth: self.global_depth,
+        }
+    }
+
+    /// Returns a copy of the current `Cell` with `global_depth` replaced by the given one
+    ///
+    /// This is a pure operation on global position, so no bounds are checked
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Cell;
+    ///
+    /// let cell = Cell::new(7, 2);
+    /// assert_eq!(cell.with_depth(255), Cell::new(7, 255));
+    /// assert_eq!(cell.with_depth(4).with_width(3), Cell::new(3, 4));
+    /// assert_eq!(cell.with_depth(4).with_depth(2), cell);
+    /// ```
+    pub const fn with_depth(self, global_depth: u8) -> Cell {
+        Cell {
+            global_width: self.global_width,
+            global_depth,
+        }
+    }
+
+    /// Maps current `Cell` into the given `Grid` toroidally, reducing its position relative to the `Grid` `start`
+    /// modulo the `Grid` `width` and `depth`
+    ///
+    /// Unlike the `wrapping` operations, this takes any `Cell` in global space, so it doesn't need to be within the `Grid`:
+    /// the `Cell` far to the right of the `Grid` reappears from the left, and the `Cell` above the `Grid` reappears from the bottom
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::indented(5, 4, (10, 10)); // 5x4 grid starting at (10,10)
+    /// assert_eq!(Cell::new(12, 11).wrap_into(grid), Cell::new(12, 11));
+    ///
+    /// // above the `end`:
+    /// assert_eq!(Cell::new(15, 14).wrap_into(grid), Cell::new(10, 10));
+    /// assert_eq!(Cell::new(255, 255).wrap_into(grid), Cell::new(10, 11));
+    ///
+    /// // below the `start`:
+    /// assert_eq!(Cell::new(9, 9).wrap_into(grid), Cell::new(14, 13));
+    /// assert_eq!(Cell::new(0, 0).wrap_into(grid), Cell::new(10, 12));
+    /// ```
+    pub fn wrap_into(self, grid: Grid) -> Cell {
+        let wrap = |global: u8, start: u8, end: u8| {
+            let span = end as i16 - start as i16 + 1;
+            (start as i16 + (global as i16 - start as i16).rem_euclid(span)) as u8
+        };
+        Cell {
+            global_width: wrap(
+                self.global_width,
+                grid.start.global_width,
+                grid.end.global_width,
+            ),
+            global_depth: wrap(
+                self.global_depth,
+                grid.start.global_depth,
+                grid.end.global_depth,
+            ),
+        }
+    }
+
+    /// Reflects current `Cell` across the `pivot` in global space,
+    /// so the `pivot` becomes the midpoint of the current `Cell` and the result
+    ///
+    /// # Note
+    /// The result saturates at the global space bounds `0..=255` on each axis,
+    /// so reflecting twice returns the original `Cell` only if no saturation occurred
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Cell;
+    ///
+    /// let (cell, pivot) = (Cell::new(2, 7), Cell::new(5, 5));
+    /// let reflected = cell.reflect_across(pivot);
+    /// assert_eq!(reflected, Cell::new(8, 3));
+    /// assert_eq!(reflected.midpoint(cell), pivot);
+    /// assert_eq!(reflected.reflect_across(pivot), cell);
+    /// assert_eq!(pivot.reflect_across(pivot), pivot);
+    ///
+    /// // saturates at the global space bounds:
+    /// assert_eq!(Cell::new(10, 250).reflect_across(Cell::new(3, 253)), Cell::new(0, 255));
+    /// ```
+    pub fn reflect_across(self, pivot: Cell) -> Cell {
+        let reflect =
+            |global: u8, pivot: u8| (2 * pivot as i16 - global as i16).clamp(0, 255) as u8;
+        Cell {
+            global_width: reflect(self.global_width, pivot.global_width),
+            global_depth: reflect(self.global_depth, pivot.global_depth),
+        }
+    }
+
+    /// Maps current `Cell` from the `from` `Grid` into the proportionally scaled position on the given `Grid`
+    ///
+    /// Relative position on each axis is scaled by `(to_length - 1) / (from_length - 1)` and rounded to the nearest `Cell`,
+    /// so corners of the `from` `Grid` are always mapped to corners of the given `Grid`
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the `from` `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let from = Grid::new(2, 2);
+    /// let grid = from.scale(3);
+    /// assert_eq!(from.start().scale_in(grid, from), grid.start());
+    /// assert_eq!(from.end().scale_in(grid, from), grid.end());
+    /// assert_eq!(Cell::new(1, 0).scale_in(grid, from), Cell::new(5, 0));
+    ///
+    /// let from = Grid::indented(5, 5, (10, 10));
+    /// let grid = Grid::indented(9, 3, (1, 1));
+    /// assert_eq!(Cell::new(12, 12).scale_in(grid, from), Cell::new(5, 2)); // center to center
+    /// assert_eq!(Cell::new(11, 13).scale_in(grid, from), Cell::new(3, 3));
+    ///
+    /// // single cell grid maps everything to the start:
+    /// let single = Grid::indented(1, 1, (4, 4));
+    /// assert_eq!(single.start().scale_in(grid, single), grid.start());
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let from = Grid::new(2, 2);
+    /// Cell::new(2, 0).scale_in(from.scale(3), from); // panic!
+    /// ```
+    pub fn scale_in(self, grid: Grid, from: Grid) -> Cell {
+        self.within_panic(from);
+        let scale = |position: u8, from_length: u8, to_length: u8| {
+            if from_length == 1 {
+                return 0;
+            }
+            let (from_span, to_span) = (from_length as u32 - 1, to_length as u32 - 1);
+            ((2 * position as u32 * to_span + from_span) / (2 * from_span)) as u8
+        };
+        grid.member(
+            scale(self.width(from), from.width(), grid.width()),
+            scale(self.depth(from), from.depth(), grid.depth()),
+        )
+    }
+
+    /// Returns every `Cell` of the given `Grid`, which Chebyshev distance from the current `Cell` is <= `radius`,
+    /// in `Grid` order
+    ///
+    /// The area is a square, clipped to the `Grid`, and the current `Cell` is included
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the given `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let area = Cell::new(5, 5).within_radius(grid, 1);
+    /// assert_eq!(area.len(), 9);
+    /// assert_eq!(area.first(), Some(&Cell::new(4, 4)));
+    /// assert_eq!(area.last(), Some(&Cell::new(6, 6)));
+    ///
+    /// assert_eq!(Cell::new(5, 5).within_radius(grid, 0), vec![Cell::new(5, 5)]);
+    /// assert_eq!(grid.start().within_radius(grid, 2).len(), 9); // clipped at grid edges
+    /// assert_eq!(Cell::new(5, 5).within_radius(grid, 255).len(), 100);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// Cell::new(10, 5).within_radius(grid, 1); // panic!
+    /// ```
+    pub fn within_radius(self, grid: Grid, radius: u8) -> Vec<Cell> {
+        self.within_panic(grid);
+        let area = Grid {
+            start: Cell {
+                global_width: self
+                    .global_width
+                    .saturating_sub(radius)
+                    .max(grid.start.global_width),
+                global_depth: self
+                    .global_depth
+                    .saturating_sub(radius)
+                    .max(grid.start.global_depth),
+            },
+            end: Cell {
+                global_width: self
+                    .global_width
+                    .saturating_add(radius)
+                    .min(grid.end.global_width),
+                global_depth: self
+                    .global_depth
+                    .saturating_add(radius)
+                    .min(grid.end.global_depth),
+            },
+        };
+        area.cells().collect()
+    }
+
+    /// Returns every `Cell` of the given `Grid`, which Manhattan distance from the current `Cell` is <= `radius`,
+    /// in `Grid` order
+    ///
+    /// The area is a diamond, clipped to the `Grid`, and the current `Cell` is included
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the given `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let area = Cell::new(5, 5).within_radius_manhattan(grid, 1);
+    /// assert_eq!(area, vec![
+    ///     Cell::new(5, 4),
+    ///     Cell::new(4, 5),
+    ///     Cell::new(5, 5),
+    ///     Cell::new(6, 5),
+    ///     Cell::new(5, 6),
+    /// ]);
+    ///
+    /// assert_eq!(Cell::new(5, 5).within_radius_manhattan(grid, 2).len(), 13);
+    /// assert_eq!(grid.start().within_radius_manhattan(grid, 2).len(), 6); // clipped at grid edges
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// Cell::new(5, 10).within_radius_manhattan(grid, 1); // panic!
+    /// ```
+    pub fn within_radius_manhattan(self, grid: Grid, radius: u8) -> Vec<Cell> {
+        self.within_radius(grid, radius)
+            .into_iter()
+            .filter(|&cell| self.manhattan_distance(cell) <= radius as u16)
+            .collect()
+    }
+}
+
+impl Ord for Cell {
+    /// implements row-major ordering for `Cell`: `global_depth` is compared first, and then `global_width`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// assert!(Cell::new(9, 0) < Cell::new(0, 1));
+    /// assert!(Cell::new(1, 3) < Cell::new(2, 3));
+    ///
+    /// let mut cells = vec![
+    ///     Cell::new(2, 1),
+    ///     Cell::new(0, 2),
+    ///     Cell::new(1, 0),
+    ///     Cell::new(0, 0),
+    ///     Cell::new(2, 2),
+    ///     Cell::new(1, 1),
+    /// ];
+    /// cells.sort();
+    /// assert_eq!(cells, vec![
+    ///     Cell::new(0, 0),
+    ///     Cell::new(1, 0),
+    ///     Cell::new(1, 1),
+    ///     Cell::new(2, 1),
+    ///     Cell::new(0, 2),
+    ///     Cell::new(2, 2),
+    /// ]);
+    ///
+    /// // same order as `Cells` iterator:
+    /// let grid = Grid::indented(4, 3, (1, 2));
+    /// let mut shuffled: Vec<Cell> = grid.cells().step_by(2).chain(grid.cells().skip(1).step_by(2)).collect();
+    /// shuffled.sort();
+    /// assert_eq!(shuffled, grid.cells().collect::<Vec<Cell>>());
+    /// ```
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.global_depth
+            .cmp(&other.global_depth)
+            .then_with(|| self.global_width.cmp(&other.global_width))
+    }
+}
+
+impl PartialOrd for Cell {
+    /// implements row-major partial ordering for `Cell`, consistent with `Ord`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Cell;
+    ///
+    /// assert!(Cell::new(5, 5) <= Cell::new(5, 5));
+    /// assert!(Cell::new(0, 6) > Cell::new(5, 5));
+    /// ```
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Cell {
+    /// implements display for `Cell`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Cell;
+    ///
+    /// let cell = Cell::new(5, 6);
+    /// assert_eq!(format!("{cell}"), "(5, 6)");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "({w}, {d})",
+            w = self.global_width,
+            d = self.global_depth
+        )
+    }
+}
+
+impl From<(u8, u8)> for Cell {
+    /// implements constructor for `Cell` from (u8, u8)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Cell;
+    ///
+    /// let pos = (5, 6);
+    /// let cell = Cell::from(pos);
+    /// assert_eq!((pos.0, pos.1), (cell.global_width(), cell.global_depth()));
+    /// ```
+    fn from(value: (u8, u8)) -> Self {
+        Self {
+            global_width: value.0,
+            global_depth: value.1,
+        }
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<(u8, u8)> for Cell {
+    /// implements conversion from `Cell` into (u8, u8)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Cell;
+    ///
+    /// let cell = Cell::new(5, 6);
+    /// let pos: (u8, u8) = cell.into();
+    /// assert_eq!((pos.0, pos.1), (cell.global_width(), cell.global_depth()));
+    /// ```
+    fn into(self) -> (u8, u8) {
+        (self.global_width, self.global_depth)
+    }
+}
+
+impl Add<(i8, i8)> for Cell {
+    type Output = Cell;
+
+    /// implements shifting of `Cell` by the signed (width, depth) offset in global space,
+    /// negative offsets move `Cell` up and left
+    ///
+    /// # Note
+    /// Unlike the movement methods, this operation does not depend on the `Grid`,
+    /// and it saturates at global position bounds (0 and 255) instead of grid bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Cell;
+    ///
+    /// assert_eq!(Cell::new(5, 5) + (-3, 2), Cell::new(2, 7));
+    /// assert_eq!(Cell::new(5, 250) + (-10, 10), Cell::new(0, 255));
+    /// ```
+    fn add(self, offset: (i8, i8)) -> Self::Output {
+        Cell {
+            global_width: self.global_width.saturating_add_signed(offset.0),
+            global_depth: self.global_depth.saturating_add_signed(offset.1),
+        }
+    }
+}
+
+impl Sub<(i8, i8)> for Cell {
+    type Output = Cell;
+
+    /// implements shifting of `Cell` by the negated signed (width, depth) offset in global space,
+    /// positive offsets move `Cell` up and left
+    ///
+    /// # Note
+    /// Unlike the movement methods, this operation does not depend on the `Grid`,
+    /// and it saturates at global position bounds (0 and 255) instead of grid bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Cell;
+    ///
+    /// assert_eq!(Cell::new(5, 5) - (3, -2), Cell::new(2, 7));
+    /// assert_eq!(Cell::new(2, 3) - (5, 3), Cell::new(0, 0));
+    /// assert_eq!(Cell::new(2, 255) - (-128, -1), Cell::new(130, 255));
+    /// ```
+    fn sub(self, offset: (i8, i8)) -> Self::Output {
+        let shift = |position: u8, offset: i8| {
+            (position as i16 - offset as i16).clamp(0, u8::MAX as i16) as u8
+        };
+        Cell {
+            global_width: shift(self.global_width, offset.0),
+            global_depth: shift(self.global_depth, offset.1),
+        }
+    }
+}
+
+impl Grid {
+    /// Creates new `Grid` with specified `width: u8` and `depth: u8`, starting at (0,0)
+    ///
+    /// # Panics
+    /// Panics if `width` or `depth` parameters < 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// assert_eq!(format!("{grid}"), "[(0, 0):(9, 9)]");
+    /// ```
+    ///
+    /// `Grid` can be created in const context:
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// const BOARD: Grid = Grid::new(8, 8);
+    /// const CORNER: Cell = Cell::new(7, 7);
+    /// const CORNER_DEPTH: u8 = CORNER.global_depth();
+    ///
+    /// assert_eq!(BOARD.end(), CORNER);
+    /// assert_eq!(CORNER_DEPTH, 7);
+    /// ```
+    pub const fn new(width: u8, depth: u8) -> Self {
+        match Grid::try_new(width, depth) {
+            Ok(grid) => grid,
+            Err(_) => panic!("can't create grid with width < 0 or depth < 0!"),
+        }
+    }
+
+    /// Creates new `Grid` with specified `width: u8` and `depth: u8`, starting at (0,0)
+    ///
+    /// This is a non-panicking counterpart of the `new` method
+    ///
+    /// # Errors
+    /// Returns `GridError::EmptyDimension`, if `width` or `depth` < 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Grid, GridError};
+    ///
+    /// assert_eq!(Grid::try_new(10, 5), Ok(Grid::new(10, 5)));
+    /// assert_eq!(Grid::try_new(0, 5), Err(GridError::EmptyDimension));
+    /// assert_eq!(Grid::try_new(10, 0), Err(GridError::EmptyDimension));
+    ///
+    /// fn board(width: u8, depth: u8) -> Result<u16, GridError> {
+    ///     Ok(Grid::try_new(width, depth)?.size())
+    /// }
+    /// assert_eq!(board(8, 8), Ok(64));
+    /// assert_eq!(board(8, 0), Err(GridError::EmptyDimension));
+    /// ```
+    pub const fn try_new(width: u8, depth: u8) -> Result<Self, GridError> {
+        Grid::try_indented(width, depth, (0, 0))
+    }
+
+    /// Creates new `Grid` with specified `width: u8` and `depth: u8`, starting at indent
+    ///
+    /// # Panics
+    /// Panics if `width` or `depth` parameters < 1
+    /// Panics if the `Grid` end exceeds `u8` global position bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Grid, Cell};
+    ///
+    /// let grid = Grid::indented(5, 5, (2, 2));
+    /// assert_eq!(format!("{grid}"), "[(2, 2):(6, 6)]");
+    ///
+    /// // use `Cell` as indent:
+    /// let cell = Cell::new(2, 2);
+    /// let grid = Grid::indented(5, 5, cell.into());
+    /// assert_eq!(format!("{grid}"), "[(2, 2):(6, 6)]");
+    /// ```
+    pub const fn indented(width: u8, depth: u8, indent: (u8, u8)) -> Self {
+        match Grid::try_indented(width, depth, indent) {
+            Ok(grid) => grid,
+            Err(GridError::EmptyDimension) => {
+                panic!("can't create grid with width < 0 or depth < 0!")
+            }
+            Err(_) => panic!("grid overflows global position bounds!"),
+        }
+    }
+
+    /// Creates new `Grid` with specified `width: u8` and `depth: u8`, starting at indent
+    ///
+    /// This is a non-panicking counterpart of the `indented` method
+    ///
+    /// # Errors
+    /// Returns `GridError::EmptyDimension`, if `width` or `depth` < 1
+    /// Returns `GridError::DimensionOverflow`, if the `Grid` end exceeds `u8` global position bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Grid, GridError};
+    ///
+    /// assert_eq!(Grid::try_indented(5, 5, (2, 2)), Ok(Grid::indented(5, 5, (2, 2))));
+    /// assert_eq!(Grid::try_indented(0, 5, (2, 2)), Err(GridError::EmptyDimension));
+    /// assert_eq!(Grid::try_indented(5, 0, (2, 2)), Err(GridError::EmptyDimension));
+    /// assert_eq!(Grid::try_indented(5, 5, (252, 2)), Err(GridError::DimensionOverflow));
+    /// ```
+    pub const fn try_indented(width: u8, depth: u8, indent: (u8, u8)) -> Result<Self, GridError> {
+        if width < 1 || depth < 1 {
+            return Err(GridError::EmptyDimension);
+        }
+        match (
+            indent.0.checked_add(width - 1),
+            indent.1.checked_add(depth - 1),
+        ) {
+            (Some(global_width), Some(global_depth)) => Ok(Self {
+                start: Cell {
+                    global_width: indent.0,
+                    global_depth: indent.1,
+                },
+                end: Cell {
+                    global_width,
+                    global_depth,
+                },
+            }),
+            _ => Err(GridError::DimensionOverflow),
+        }
+    }
+
+    /// Creates new `Grid` with specified `width: u8` and `depth: u8`, which `center` is at the given global position
+    ///
+    /// The `center` is the `Cell` at the relative position `(width / 2, depth / 2)`, same as in `Grid::center`.
+    /// If centering would underflow the global space, the `start` saturates at 0 on the corresponding axis
+    ///
+    /// # Panics
+    /// Panics if `width` or `depth` parameters < 1
+    /// Panics if the `Grid` overflows `u8` global position bounds, when the `center` is too close to 255
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::centered(3, 3, (5, 5));
+    /// assert_eq!(grid.start(), Cell::new(4, 4));
+    /// assert_eq!(grid.center(), Cell::new(5, 5));
+    ///
+    /// let grid = Grid::centered(4, 2, (5, 5));
+    /// assert_eq!(grid, Grid::indented(4, 2, (3, 4)));
+    /// assert_eq!(grid.center(), Cell::new(5, 5));
+    ///
+    /// // start is clamped near the origin:
+    /// let grid = Grid::centered(7, 5, (1, 0));
+    /// assert_eq!(grid, Grid::new(7, 5));
+    /// assert_eq!(grid.center(), Cell::new(3, 2));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::Grid;
+    ///
+    /// Grid::centered(0, 3, (5, 5)); // panic!
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::Grid;
+    ///
+    /// Grid::centered(7, 5, (254, 254)); // panic!
+    /// ```
+    pub fn centered(width: u8, depth: u8, center: (u8, u8)) -> Self {
+        Grid::indented(
+            width,
+            depth,
+            (
+                center.0.saturating_sub(width / 2),
+                center.1.saturating_sub(depth / 2),
+            ),
+        )
+    }
+
+    /// Checks if the `Grid` is within the another `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let subgrid = grid.area(5, 5);
+    /// assert!(subgrid.within(grid));
+    ///
+    /// let subgrid = Grid::new(10, 12);
+    /// assert!(!subgrid.within(grid));
+    /// ```
+    pub fn within(self, grid: Grid) -> bool {
+        self.start.within(grid) && self.end.within(grid)
+    }
+
+    /// Checks if the `Grid` is within the another `Grid`
+    ///
+    /// # Panics
+    /// Panics if the `Grid` is not within the another `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let subgrid = Grid::new(10, 12);
+    /// subgrid.within_panic(grid);
+    /// ```
+    pub fn within_panic(self, grid: Grid) {
+        if let Err(error) = self.checked_within(grid) {
+            panic!("{error}")
+        }
+    }
+
+    /// Checks if the `Grid` is within the another `Grid`
+    ///
+    /// This is a non-panicking counterpart of the `within_panic` method
+    ///
+    /// # Errors
+    /// Returns `GridError::SubgridOutOfGrid`, if the `Grid` is not within the another `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Grid, GridError};
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let subgrid = grid.area(5, 5);
+    /// assert_eq!(subgrid.checked_within(grid), Ok(()));
+    ///
+    /// let subgrid = Grid::new(10, 12);
+    /// assert_eq!(subgrid.checked_within(grid), Err(GridError::SubgridOutOfGrid { subgrid, grid }));
+    /// ```
+    pub fn checked_within(self, grid: Grid) -> Result<(), GridError> {
+        if self.within(grid) {
+            Ok(())
+        } else {
+            Err(GridError::SubgridOutOfGrid {
+                subgrid: self,
+                grid,
+            })
+        }
+    }
+
+    /// Returns new `Cell` by `width: u8` and `depth: u8` relative to the current `Grid`
+    ///
+    /// # Panics
+    /// Panics if `width` or `depth` of the requested member exceeds borders of the current `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Grid, Cell};
+    ///
+    /// let grid = Grid::indented(5, 5, (2, 2)); // 5x5 grid, starting at (2,2)
+    /// let member = grid.member(4, 4);
+    /// assert_eq!(member, Cell::new(6, 6));
+    /// ```
+    pub fn member(self, width: u8, depth: u8) -> Cell {
+        self.checked_member(width, depth).unwrap_or_else(|| {
+            panic!("member is not within given grid! width:{width}, depth:{depth}, grid:{self}")
+        })
+    }
+
+    /// Returns new `Cell` by `width: u8` and `depth: u8` relative to the current `Grid`,
+    /// or `None` if the requested member exceeds borders of the current `Grid`
+    ///
+    /// This is a non-panicking counterpart of the `member` method
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Grid, Cell};
+    ///
+    /// let grid = Grid::indented(5, 3, (2, 2)); // 5x3 grid, starting at (2,2)
+    /// assert_eq!(grid.checked_member(4, 2), Some(Cell::new(6, 4)));
+    /// assert_eq!(grid.checked_member(5, 0), None); // width is 5, so the last index is 4
+    /// assert_eq!(grid.checked_member(0, 3), None);
+    /// ```
+    pub fn checked_member(self, width: u8, depth: u8) -> Option<Cell> {
+        self.start
+            .try_right(self, width)
+            .and_then(|cell| cell.try_down(self, depth))
+            .ok()
+    }
+
+    /// Returns new `Grid` with `width: u8` and `depth: u8`, which is a subgrid
+    /// of current `Grid`, starting at current `Grid` start
+    ///
+    /// # Panics
+    /// Panics if `width` or `depth` parameters < 1
+    /// Panics if `width` or `depth` of the requested area exceeds borders of the current `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Grid, Cell};
+    ///
+    /// let grid = Grid::indented(5, 5, (2, 2)); // 5x5 grid, starting at (2,2)
+    /// let area = grid.area(3, 3);
+    /// assert_eq!(format!("{area}"), "[(2, 2):(4, 4)]");
+    /// ```
+    pub fn area(self, width: u8, depth: u8) -> Grid {
+        if width < 1 || depth < 1 {
+            panic!("can't create grid with width < 0 or depth < 0!")
+        }
+        Grid {
+            start: self.start,
+            end: self
+                .start
+                .strict_right(self, width - 1)
+                .strict_down(self, depth - 1),
+        }
+    }
+
+    /// Returns new `Grid` with `width: u8` and `depth: u8`, which is a subgrid
+    /// of current `Grid`, starting at current `Grid` start + indent
+    ///
+    /// # Panics
+    /// Panics if `width` or `depth` parameters < 1
+    /// Panics if `width` or `depth` of the requested slice exceeds borders of the current `Grid`
+    /// Panics if `indent` of the requested slice exceeds borders of the current `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Grid, Cell};
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let slice = grid.slice(3, 3, (2, 2));
+    /// assert_eq!(format!("{slice}"), "[(2, 2):(4, 4)]");
+    ///
+    /// // use `Cell` as indent:
+    /// let cell = Cell::new(2, 2);
+    /// let slice = grid.slice(3, 3, cell.into());
+    /// assert_eq!(format!("{slice}"), "[(2, 2):(4, 4)]");
+    /// ```
+    pub fn slice(self, width: u8, depth: u8, indent: (u8, u8)) -> Grid {
+        if width < 1 || depth < 1 {
+            panic!("can't create grid with width < 0 or depth < 0!")
+        }
+        Grid {
+            start: self
+                .start
+                .strict_right(self, indent.0)
+                .strict_down(self, indent.1),
+            end: self
+                .start
+                .strict_right(self, indent.0 + width - 1)
+                .strict_down(self, indent.1 + depth - 1),
+        }
+    }
+
+    /// Returns `start` cell of `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Grid, Cell};
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let start = grid.start();
+    /// assert_eq!(start, Cell::new(0, 0));
+    /// ```
+    pub fn start(self) -> Cell {
+        self.start
+    }
+
+    /// Returns `end` cell of `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Grid, Cell};
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let end = grid.end();
+    /// assert_eq!(end, Cell::new(9, 9));
+    /// ```
+    pub fn end(self) -> Cell {
+        self.end
+    }
+
+    /// Calculates `width` of `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let width = grid.width();
+    /// assert_eq!(width, 10);
+    /// ```
+    pub fn width(self) -> u8 {
+        self.end.global_width - self.start.global_width + 1
+    }
+
+    /// Calculates `depth` of `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let depth = grid.depth();
+    /// assert_eq!(depth, 10);
+    /// ```
+    pub fn depth(self) -> u8 {
+        self.end.global_depth - self.start.global_depth + 1
+    }
+
+    /// Calculates `size: u16` of `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let size = grid.size();
+    /// assert_eq!(size, 100);
+    /// ```
+    pub fn size(self) -> u16 {
+        self.width() as u16 * self.depth() as u16
+    }
+
+    /// Returns `width` and `depth` of `Grid` as a tuple
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::new(10, 4);
+    /// let (width, depth) = grid.dimensions();
+    /// assert_eq!((width, depth), (10, 4));
+    ///
+    /// for grid in [Grid::new(1, 1), Grid::indented(7, 3, (2, 5)), Grid::from(((3, 1), (9, 2)))] {
+    ///     assert_eq!(grid.dimensions(), (grid.width(), grid.depth()));
+    /// }
+    /// ```
+    pub fn dimensions(self) -> (u8, u8) {
+        (self.width(), self.depth())
+    }
+
+    /// Returns `Cells`, which is an iterator over every cell of the `Grid`
+    ///
+    /// # Examples
+    ///
+    /// Get every `Cell` on `width` and `depth` axis:
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(3, 3);
+    ///
+    /// let axis_cells: Vec<Cell> = grid
+    ///     .cells()
+    ///     .filter(|cell| {
+    ///         cell.global_width() == grid.start().global_width() || cell.global_depth() == grid.start().global_depth()
+    ///     })
+    ///     .collect();
+    /// assert_eq!(axis_cells, vec![
+    ///     Cell::new(0, 0),
+    ///     Cell::new(1, 0),
+    ///     Cell::new(2, 0),
+    ///     Cell::new(0, 1),
+    ///     Cell::new(0, 2),
+    /// ]);
+    /// ```
+    pub fn cells(self) -> Cells {
+        Cells::from(self)
+    }
+
+    /// Returns `Rows`, which is an iterator over every row of the `Grid`
+    ///
+    /// # Examples
+    ///
+    /// Print out `Grid` in custom format:
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(3, 3);
+    /// let grid_string = grid
+    ///     .rows()
+    ///     .map(|row| {
+    ///         row.cells().map(|_| " [#]")
+    ///             .chain(std::iter::once("\n\n"))
+    ///             .collect::<String>()
+    ///     })
+    ///     .collect::<String>();
+    /// assert_eq!(grid_string,
+    /// " \
+    ///  [#] [#] [#]
+    ///
+    ///  [#] [#] [#]
+    ///
+    ///  [#] [#] [#]
+    ///
+    /// "
+    /// );
+    /// ```
+    pub fn rows(self) -> Rows {
+        Rows::from(self)
+    }
+
+    /// Returns `Columns`, which is an iterator over every column of the `Grid`
+    ///
+    /// # Examples
+    ///
+    /// Get every `Cell` on the first column of `Grid`:
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(3, 3);
+    ///
+    /// let first_column_cells: Vec<Cell> = grid
+    ///     .columns()
+    ///     .next()
+    ///     .unwrap()
+    ///     .cells()
+    ///     .collect();
+    ///
+    /// assert_eq!(first_column_cells, vec![
+    ///     Cell::new(0, 0),
+    ///     Cell::new(0, 1),
+    ///     Cell::new(0, 2),
+    /// ]);
+    /// ```
+    pub fn columns(self) -> Columns {
+        Columns::from(self)
+    }
+
+    /// Returns an iterator over every `Cell` of the `Grid`, that is not on the edge of the `Grid`
+    ///
+    /// # Note
+    /// For `Grid`s with `width` or `depth` <= 2 this yields nothing
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let interior: Vec<Cell> = grid.interior().collect();
+    /// assert_eq!(interior, grid.slice(3, 3, (1, 1)).cells().collect::<Vec<Cell>>());
+    ///
+    /// let grid = Grid::new(2, 2);
+    /// assert_eq!(grid.interior().count(), 0);
+    /// ```
+    pub fn interior(self) -> Filter<Cells, impl FnMut(&Cell) -> bool> {
+        self.cells().filter(move |cell| !cell.on_the_edge(self))
+    }
+
+    /// Returns given `Cell`s alongside with their reflections across the center of the `Grid` on the given `axis`
+    ///
+    /// `Axis::Width` reflects `Cell`s from the left side to the right side and backwards,
+    /// `Axis::Depth` reflects `Cell`s from the top side to the bottom side and backwards
+    ///
+    /// # Note
+    /// Given `Cell`s go first, and then go their reflections. Duplicates are removed
+    ///
+    /// # Panics
+    /// Panics if any of the given `Cell`s is not within the `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Axis, Cell, Grid};
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let cells = grid.symmetrize(&[Cell::new(0, 1)], Axis::Width);
+    /// assert_eq!(cells, vec![Cell::new(0, 1), Cell::new(4, 1)]);
+    ///
+    /// let cells = grid.symmetrize(&[Cell::new(0, 1)], Axis::Depth);
+    /// assert_eq!(cells, vec![Cell::new(0, 1), Cell::new(0, 3)]);
+    ///
+    /// // cell on the center line is reflected onto itself:
+    /// let cells = grid.symmetrize(&[Cell::new(2, 1)], Axis::Width);
+    /// assert_eq!(cells, vec![Cell::new(2, 1)]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::{Axis, Cell, Grid};
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// grid.symmetrize(&[Cell::new(5, 1)], Axis::Width); // panic!
+    /// ```
+    pub fn symmetrize(self, cells: &[Cell], axis: Axis) -> Vec<Cell> {
+        let reflections = cells.iter().map(|&cell| match axis {
+            Axis::Width => Cell {
+                global_width: self.end.global_width - cell.width(self),
+                global_depth: cell.global_depth,
+            },
+            Axis::Depth => Cell {
+                global_width: cell.global_width,
+                global_depth: self.end.global_depth - cell.depth(self),
+            },
+        });
+        let mut seen: HashSet<Cell> = HashSet::new();
+        cells
+            .iter()
+            .copied()
+            .chain(reflections)
+            .filter(|&cell| seen.insert(cell))
+            .collect()
+    }
+
+    /// Checks if the `Grid` fits into the terminal with `cols` columns and `rows` rows,
+    /// when every `Cell` is rendered with `cell_cols` columns and 1 row
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::new(20, 10);
+    /// assert!(grid.fits_in_terminal(80, 24, 4)); // 20 * 4 = 80 columns, 10 rows
+    /// assert!(!grid.fits_in_terminal(80, 24, 5)); // 20 * 5 = 100 columns is too wide
+    /// assert!(!grid.fits_in_terminal(80, 8, 1)); // 10 rows is too deep
+    /// ```
+    pub fn fits_in_terminal(self, cols: u16, rows: u16, cell_cols: u16) -> bool {
+        self.width() as u32 * cell_cols as u32 <= cols as u32 && self.depth() as u16 <= rows
+    }
+
+    /// Returns `Cell` at the parameter `t` on the diagonal from the `Grid` start to the `Grid` end
+    ///
+    /// This operation is a wrapper around the `Cell::lerp` method
+    ///
+    /// # Panics
+    /// Panics if `t` is not within [0, 1]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::indented(5, 5, (2, 2));
+    /// assert_eq!(grid.diagonal_cell_at(0.0), grid.start());
+    /// assert_eq!(grid.diagonal_cell_at(1.0), grid.end());
+    /// assert_eq!(grid.diagonal_cell_at(0.5), Cell::new(4, 4));
+    /// ```
+    pub fn diagonal_cell_at(self, t: f64) -> Cell {
+        self.start.lerp(self.end, t)
+    }
+
+    /// Returns the nearest to the given `Cell` member of the `Grid`
+    ///
+    /// Global positions of the `Cell` are clamped into the `Grid` bounds on each axis independently,
+    /// so `Cell` that is already within the `Grid` is returned unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::indented(5, 5, (2, 2));
+    /// assert_eq!(grid.clamp(Cell::new(0, 0)), Cell::new(2, 2));
+    /// assert_eq!(grid.clamp(Cell::new(9, 4)), Cell::new(6, 4));
+    /// assert_eq!(grid.clamp(Cell::new(3, 3)), Cell::new(3, 3));
+    /// ```
+    pub fn clamp(self, cell: Cell) -> Cell {
+        Cell {
+            global_width: cell
+                .global_width
+                .clamp(self.start.global_width, self.end.global_width),
+            global_depth: cell
+                .global_depth
+                .clamp(self.start.global_depth, self.end.global_depth),
+        }
+    }
+
+    /// Returns an iterator over every `Cell` of the `Grid` alongside with its `(row, column)` indexes,
+    /// which are relative `depth` and `width` of the `Cell` as `usize`
+    ///
+    /// This is helpful for indexing external `Vec<Vec<T>>`, where the outer `Vec` holds rows
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::indented(2, 2, (3, 5));
+    /// let pairs: Vec<((usize, usize), Cell)> = grid.index_pairs().collect();
+    /// assert_eq!(pairs, vec![
+    ///     ((0, 0), Cell::new(3, 5)),
+    ///     ((0, 1), Cell::new(4, 5)),
+    ///     ((1, 0), Cell::new(3, 6)),
+    ///     ((1, 1), Cell::new(4, 6)),
+    /// ]);
+    ///
+    /// let rows = vec![vec!['a', 'b'], vec!['c', 'd']];
+    /// let chars: String = grid.index_pairs().map(|((row, column), _)| rows[row][column]).collect();
+    /// assert_eq!(chars, "abcd");
+    /// ```
+    pub fn index_pairs(self) -> Map<Cells, impl FnMut(Cell) -> ((usize, usize), Cell)> {
+        self.cells()
+            .map(move |cell| ((cell.depth(self) as usize, cell.width(self) as usize), cell))
+    }
+
+    /// Returns new `Grid` with swapped `width` and `depth`, which is the `Grid` rotated by 90 degrees clockwise
+    ///
+    /// The rotated `Grid` has the same `start`, use `Cell::rotate_90_cw` to map cells onto it
+    ///
+    /// # Panics
+    /// Panics if the rotated `Grid` overflows `u8` global position bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::indented(5, 3, (1, 2));
+    /// assert_eq!(grid.rotate_90_cw(), Grid::indented(3, 5, (1, 2)));
+    /// ```
+    pub fn rotate_90_cw(self) -> Grid {
+        GridBuilder::new()
+            .width(self.depth())
+            .depth(self.width())
+            .indent(self.start.into())
+            .build()
+            .unwrap_or_else(|error| panic!("can't rotate grid! grid:{self}, error:{error}"))
+    }
+
+    /// Returns new `Grid` with swapped `width` and `depth`, which is the `Grid` rotated by 90 degrees counterclockwise
+    ///
+    /// The rotated `Grid` has the same `start`, so it is equal to `rotate_90_cw`,
+    /// use `Cell::rotate_90_ccw` to map cells onto it
+    ///
+    /// # Panics
+    /// Panics if the rotated `Grid` overflows `u8` global position bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::indented(5, 3, (1, 2));
+    /// assert_eq!(grid.rotate_90_ccw(), Grid::indented(3, 5, (1, 2)));
+    /// ```
+    pub fn rotate_90_ccw(self) -> Grid {
+        self.rotate_90_cw()
+    }
+
+    /// Creates new `Grid` from the given `start` and `end` cells
+    ///
+    /// This is a non-panicking counterpart of the `From<(Cell, Cell)>` implementation
+    ///
+    /// # Errors
+    /// Returns `GridError::StartAfterEnd`, if `start` overflows `end` on any axis
+    /// Returns `GridError::DimensionOverflow`, if the `Grid` is 256 cells wide or deep
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid, GridError};
+    ///
+    /// let (start, end) = (Cell::new(1, 2), Cell::new(5, 6));
+    /// assert_eq!(Grid::checked_from_bounds(start, end), Ok(Grid::from((start, end))));
+    /// assert_eq!(
+    ///     Grid::checked_from_bounds(end, start),
+    ///     Err(GridError::StartAfterEnd { start: end, end: start })
+    /// );
+    /// assert_eq!(
+    ///     Grid::checked_from_bounds(Cell::new(0, 0), Cell::new(255, 5)),
+    ///     Err(GridError::DimensionOverflow)
+    /// );
+    /// ```
+    pub const fn checked_from_bounds(start: Cell, end: Cell) -> Result<Grid, GridError> {
+        if start.global_width > end.global_width || start.global_depth > end.global_depth {
+            return Err(GridError::StartAfterEnd { start, end });
+        }
+        if end.global_width - start.global_width == u8::MAX
+            || end.global_depth - start.global_depth == u8::MAX
+        {
+            return Err(GridError::DimensionOverflow);
+        }
+        Ok(Grid { start, end })
+    }
+
+    /// Returns an iterator over every row of the `Grid`, where every row is a `Vec<Cell>`,
+    /// and rows alternate their direction: even rows go from left to right, odd rows go from right to left
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(3, 3);
+    /// let rows: Vec<Vec<Cell>> = grid.rows_boustrophedon().collect();
+    /// assert_eq!(rows, vec![
+    ///     vec![Cell::new(0, 0), Cell::new(1, 0), Cell::new(2, 0)],
+    ///     vec![Cell::new(2, 1), Cell::new(1, 1), Cell::new(0, 1)],
+    ///     vec![Cell::new(0, 2), Cell::new(1, 2), Cell::new(2, 2)],
+    /// ]);
+    /// ```
+    pub fn rows_boustrophedon(self) -> impl Iterator<Item = Vec<Cell>> {
+        self.rows().enumerate().map(|(index, row)| {
+            let mut cells: Vec<Cell> = row.cells().collect();
+            if index % 2 == 1 {
+                cells.reverse();
+            }
+            cells
+        })
+    }
+
+    /// Renders the `Grid` into the `String`, where every `Cell` is rendered with `cell_str`,
+    /// cells within the row are joined with `col_sep`, and rows are joined with `row_sep`
+    ///
+    /// # Note
+    /// Separators are placed only between cells and rows, so there is no trailing `row_sep`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(3, 3);
+    /// let grid_string = grid.render_ascii(|_| " [#]".to_string(), "", "\n\n") + "\n\n";
+    /// assert_eq!(grid_string,
+    /// " \
+    ///  [#] [#] [#]
+    ///
+    ///  [#] [#] [#]
+    ///
+    ///  [#] [#] [#]
+    ///
+    /// "
+    /// );
+    ///
+    /// let grid_string = grid.render_ascii(
+    ///     |cell| if cell.at(grid.start()) { "@".to_string() } else { ".".to_string() },
+    ///     " ",
+    ///     "\n",
+    /// );
+    /// assert_eq!(grid_string, "@ . .\n. . .\n. . .");
+    /// ```
+    pub fn render_ascii(
+        self,
+        cell_str: impl Fn(Cell) -> String,
+        col_sep: &str,
+        row_sep: &str,
+    ) -> String {
+        self.rows()
+            .map(|row| {
+                row.cells()
+                    .map(&cell_str)
+                    .collect::<Vec<String>>()
+                    .join(col_sep)
+            })
+            .collect::<Vec<String>>()
+            .join(row_sep)
+    }
+
+    /// Returns `Ring`, which is an iterator over every `Cell` on the perimeter of the `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(4, 3);
+    /// let frame: Vec<Cell> = grid.ring().collect();
+    /// assert_eq!(frame.len(), 10);
+    /// assert!(frame.iter().all(|cell| cell.on_the_edge(grid)));
+    ///
+    /// // corners are not repeated:
+    /// let unique: std::collections::HashSet<Cell> = frame.iter().copied().collect();
+    /// assert_eq!(unique.len(), frame.len());
+    ///
+    /// // for a line every cell is on the perimeter:
+    /// let line = Grid::new(1, 4);
+    /// assert_eq!(line.ring().collect::<Vec<Cell>>(), line.cells().collect::<Vec<Cell>>());
+    /// let line = Grid::new(4, 1);
+    /// assert_eq!(line.ring().collect::<Vec<Cell>>(), line.cells().collect::<Vec<Cell>>());
+    ///
+    /// // single cell grid yields the cell once:
+    /// let single = Grid::new(1, 1);
+    /// assert_eq!(single.ring().collect::<Vec<Cell>>(), vec![single.start()]);
+    /// ```
+    pub fn ring(self) -> Ring {
+        Ring::from(self)
+    }
+
+    /// Returns `BorderCells`, which is an iterator over every `Cell` on the perimeter of the `Grid`,
+    /// paired with the `Edge` the `Cell` belongs to
+    ///
+    /// # Note
+    /// For the `Grid` with `width` or `depth` of 1, every `Cell` belongs to the opposite borders at once,
+    /// so the `Top` and the `Left` borders take priority over the `Bottom` and the `Right` ones
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Corner, Edge, Grid};
+    ///
+    /// let grid = Grid::indented(5, 5, (1, 1));
+    /// let corners: Vec<(Cell, Edge)> = grid
+    ///     .border_cells()
+    ///     .filter(|(_, edge)| matches!(edge, Edge::Corner(_)))
+    ///     .collect();
+    /// assert_eq!(corners, vec![
+    ///     (Cell::new(1, 1), Edge::Corner(Corner::TopLeft)),
+    ///     (Cell::new(5, 1), Edge::Corner(Corner::TopRight)),
+    ///     (Cell::new(5, 5), Edge::Corner(Corner::BottomRight)),
+    ///     (Cell::new(1, 5), Edge::Corner(Corner::BottomLeft)),
+    /// ]);
+    /// assert_eq!(grid.border_cells().count(), grid.ring().count());
+    ///
+    /// let line = Grid::new(3, 1);
+    /// let edges: Vec<Edge> = line.border_cells().map(|(_, edge)| edge).collect();
+    /// assert_eq!(edges, vec![
+    ///     Edge::Corner(Corner::TopLeft),
+    ///     Edge::Top,
+    ///     Edge::Corner(Corner::TopRight),
+    /// ]);
+    /// ```
+    pub fn border_cells(self) -> BorderCells {
+        BorderCells::from(self)
+    }
+
+    /// Returns `Spiral`, which is an iterator over every `Cell` of the `Grid`, going in the inward clockwise spiral
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    /// use std::collections::HashSet;
+    ///
+    /// let grid = Grid::indented(6, 4, (2, 3));
+    /// let spiral: Vec<Cell> = grid.spiral().collect();
+    /// assert_eq!(spiral.len(), grid.size() as usize);
+    /// assert_eq!(spiral.first(), Some(&grid.start()));
+    ///
+    /// let unique: HashSet<Cell> = spiral.into_iter().collect();
+    /// assert_eq!(unique.len(), grid.size() as usize);
+    ///
+    /// // peels rings from the outside in, ending at the center:
+    /// let grid = Grid::indented(3, 3, (1, 1));
+    /// let spiral: Vec<Cell> = grid.spiral().collect();
+    /// assert_eq!(spiral, vec![
+    ///     Cell::new(1, 1),
+    ///     Cell::new(2, 1),
+    ///     Cell::new(3, 1),
+    ///     Cell::new(3, 2),
+    ///     Cell::new(3, 3),
+    ///     Cell::new(2, 3),
+    ///     Cell::new(1, 3),
+    ///     Cell::new(1, 2),
+    ///     Cell::new(2, 2),
+    /// ]);
+    /// ```
+    pub fn spiral(self) -> Spiral {
+        Spiral::from(self)
+    }
+
+    /// Splits the `Grid` into four regions around the `pivot`, excluding `pivot`'s row and column
+    ///
+    /// Regions go in the following order: top-left, top-right, bottom-left, bottom-right.
+    /// If the region is empty (`pivot` is on the edge of the `Grid`), it is `None`
+    ///
+    /// # Panics
+    /// Panics if the `pivot` is not within the `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let regions = grid.quadrants_at(grid.member(2, 1));
+    /// assert_eq!(regions, [
+    ///     Some(Grid::from(((0, 0), (1, 0)))),
+    ///     Some(Grid::from(((3, 0), (4, 0)))),
+    ///     Some(Grid::from(((0, 2), (1, 4)))),
+    ///     Some(Grid::from(((3, 2), (4, 4)))),
+    /// ]);
+    ///
+    /// let regions = grid.quadrants_at(grid.member(0, 2));
+    /// assert_eq!(regions, [
+    ///     None,
+    ///     Some(Grid::from(((1, 0), (4, 1)))),
+    ///     None,
+    ///     Some(Grid::from(((1, 3), (4, 4)))),
+    /// ]);
+    /// ```
+    pub fn quadrants_at(self, pivot: Cell) -> [Option<Grid>; 4] {
+        pivot.within_panic(self);
+        let left = (pivot.global_width > self.start.global_width)
+            .then(|| (self.start.global_width, pivot.global_width - 1));
+        let right = (pivot.global_width < self.end.global_width)
+            .then(|| (pivot.global_width + 1, self.end.global_width));
+        let top = (pivot.global_depth > self.start.global_depth)
+            .then(|| (self.start.global_depth, pivot.global_depth - 1));
+        let bottom = (pivot.global_depth < self.end.global_depth)
+            .then(|| (pivot.global_depth + 1, self.end.global_depth));
+        let region = |widths: Option<(u8, u8)>, depths: Option<(u8, u8)>| {
+            widths.zip(depths).map(|(widths, depths)| Grid {
+                start: Cell::new(widths.0, depths.0),
+                end: Cell::new(widths.1, depths.1),
+            })
+        };
+        [
+            region(left, top),
+            region(right, top),
+            region(left, bottom),
+            region(right, bottom),
+        ]
+    }
+
+    /// Splits the `Grid` into four quadrants, splitting each axis at its midpoint
+    ///
+    /// Quadrants go in the following order: top-left, top-right, bottom-left, bottom-right.
+    /// For odd dimensions the extra column goes to the left quadrants, and the extra row goes to the top ones
+    ///
+    /// # Panics
+    /// Panics if the `width` or the `depth` of the `Grid` is less than 2
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    /// use std::collections::HashSet;
+    ///
+    /// let grid = Grid::new(4, 4);
+    /// assert_eq!(grid.quadrants(), [
+    ///     Grid::indented(2, 2, (0, 0)),
+    ///     Grid::indented(2, 2, (2, 0)),
+    ///     Grid::indented(2, 2, (0, 2)),
+    ///     Grid::indented(2, 2, (2, 2)),
+    /// ]);
+    ///
+    /// let grid = Grid::indented(5, 3, (1, 2));
+    /// let quadrants = grid.quadrants();
+    /// assert_eq!(quadrants, [
+    ///     Grid::indented(3, 2, (1, 2)),
+    ///     Grid::indented(2, 2, (4, 2)),
+    ///     Grid::indented(3, 1, (1, 4)),
+    ///     Grid::indented(2, 1, (4, 4)),
+    /// ]);
+    ///
+    /// // quadrants tile the grid with no overlaps:
+    /// let mut cells: HashSet<Cell> = HashSet::new();
+    /// assert!(quadrants.iter().flat_map(|quadrant| quadrant.cells()).all(|cell| cells.insert(cell)));
+    /// assert_eq!(cells, grid.cells().collect());
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::Grid;
+    ///
+    /// Grid::new(1, 1).quadrants(); // panic!
+    /// ```
+    pub fn quadrants(self) -> [Grid; 4] {
+        if self.width() < 2 || self.depth() < 2 {
+            panic!("can't split grid into quadrants! grid:{self}")
+        }
+        let (left, right) = self.split_at_width(self.width().div_ceil(2));
+        let at = self.depth().div_ceil(2);
+        let (top_left, bottom_left) = left.split_at_depth(at);
+        let (top_right, bottom_right) = right.split_at_depth(at);
+        [top_left, top_right, bottom_left, bottom_right]
+    }
+
+    /// Checks if the `Grid` is within `margin` cells of the `other` `Grid` on both axes,
+    /// meaning that the `Grid` expanded by `margin` in every direction overlaps the `other`
+    ///
+    /// Expansion saturates at the global position bounds, so it never overflows
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::from(((0, 0), (2, 2)));
+    /// let other = Grid::from(((5, 1), (7, 3))); // 3 cells apart on width axis
+    /// assert!(grid.overlaps_within_margin(other, 3));
+    /// assert!(!grid.overlaps_within_margin(other, 2));
+    /// assert!(grid.overlaps_within_margin(grid, 0));
+    ///
+    /// let far = Grid::from(((250, 250), (255, 255)));
+    /// assert!(far.overlaps_within_margin(grid, u8::MAX));
+    /// ```
+    pub fn overlaps_within_margin(self, other: Grid, margin: u8) -> bool {
+        let overlaps = |start: u8, end: u8, other_start: u8, other_end: u8| {
+            start.saturating_sub(margin) <= other_end && end.saturating_add(margin) >= other_start
+        };
+        overlaps(
+            self.start.global_width,
+            self.end.global_width,
+            other.start.global_width,
+            other.end.global_width,
+        ) && overlaps(
+            self.start.global_depth,
+            self.end.global_depth,
+            other.start.global_depth,
+            other.end.global_depth,
+        )
+    }
+
+    /// Returns the largest `Grid` contained in both `Grid`s, or `None` if they share no `Cell`s
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::from(((0, 0), (4, 4)));
+    /// let other = Grid::from(((2, 3), (7, 7)));
+    /// assert_eq!(grid.intersection(other), Some(Grid::from(((2, 3), (4, 4)))));
+    ///
+    /// let nested = Grid::from(((1, 1), (2, 2)));
+    /// assert_eq!(grid.intersection(nested), Some(nested));
+    ///
+    /// let disjoint = Grid::from(((6, 0), (8, 2)));
+    /// assert_eq!(grid.intersection(disjoint), None);
+    ///
+    /// let touching = Grid::from(((5, 5), (6, 6))); // touches at the corner only
+    /// assert_eq!(grid.intersection(touching), None);
+    /// ```
+    pub fn intersection(self, other: Grid) -> Option<Grid> {
+        let start = Cell::new(
+            self.start.global_width.max(other.start.global_width),
+            self.start.global_depth.max(other.start.global_depth),
+        );
+        let end = Cell::new(
+            self.end.global_width.min(other.end.global_width),
+            self.end.global_depth.min(other.end.global_depth),
+        );
+        Grid::checked_from_bounds(start, end).ok()
+    }
+
+    /// Returns the smallest `Grid`, that contains every given `Cell`, or `None` if there are no `Cell`s
+    ///
+    /// # Errors
+    /// Returns `GridError::DimensionOverflow`, if the `Cell`s span 256 cells on any axis,
+    /// because such `Grid` does not fit into the `u8` global positions
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid, GridError};
+    ///
+    /// let cells = [Cell::new(4, 2), Cell::new(1, 7), Cell::new(6, 3), Cell::new(2, 5)];
+    /// let grid = Grid::bounding_box(cells).unwrap().unwrap();
+    /// assert_eq!(grid, Grid::from(((1, 2), (6, 7))));
+    /// assert!(cells.iter().all(|cell| cell.within(grid)));
+    ///
+    /// assert_eq!(Grid::bounding_box([Cell::new(3, 3)]), Ok(Some(Grid::from(((3, 3), (3, 3))))));
+    /// assert_eq!(Grid::bounding_box(Vec::new()), Ok(None));
+    ///
+    /// // full span on any axis does not fit into the `Grid`:
+    /// assert_eq!(
+    ///     Grid::bounding_box([Cell::new(0, 0), Cell::new(255, 3)]),
+    ///     Err(GridError::DimensionOverflow)
+    /// );
+    /// ```
+    pub fn bounding_box<I: IntoIterator<Item = Cell>>(cells: I) -> Result<Option<Grid>, GridError> {
+        cells
+            .into_iter()
+            .fold(None, |bounds: Option<(Cell, Cell)>, cell| {
+                Some(match bounds {
+                    None => (cell, cell),
+                    Some((start, end)) => (
+                        Cell {
+                            global_width: start.global_width.min(cell.global_width),
+                            global_depth: start.global_depth.min(cell.global_depth),
+                        },
+                        Cell {
+                            global_width: end.global_width.max(cell.global_width),
+                            global_depth: end.global_depth.max(cell.global_depth),
+                        },
+                    ),
+                })
+            })
+            .map(|(start, end)| Grid::checked_from_bounds(start, end))
+            .transpose()
+    }
+
+    /// Returns `Cell`s, that constitute the center of the `Grid`, in `Grid` order
+    ///
+    /// The center is a single `Cell` for odd x odd `Grid`, two `Cell`s if one of the dimensions is even,
+    /// and 2x2 block of `Cell`s for even x even `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// assert_eq!(Grid::new(5, 5).center_cells(), vec![Cell::new(2, 2)]);
+    /// assert_eq!(Grid::new(5, 4).center_cells(), vec![Cell::new(2, 1), Cell::new(2, 2)]);
+    /// assert_eq!(Grid::new(4, 5).center_cells(), vec![Cell::new(1, 2), Cell::new(2, 2)]);
+    /// assert_eq!(Grid::new(4, 4).center_cells(), vec![
+    ///     Cell::new(1, 1),
+    ///     Cell::new(2, 1),
+    ///     Cell::new(1, 2),
+    ///     Cell::new(2, 2),
+    /// ]);
+    /// assert_eq!(Grid::new(1, 2).center_cells().len(), 2);
+    /// ```
+    pub fn center_cells(self) -> Vec<Cell> {
+        let (width, depth) = self.dimensions();
+        let start = self.member((width - 1) / 2, (depth - 1) / 2);
+        let end = self.member(width / 2, depth / 2);
+        Grid { start, end }.cells().collect()
+    }
+
+    /// Returns `Cell` at the relative position `(width / 2, depth / 2)` of the `Grid`
+    ///
+    /// For even dimensions this is the last of the `center_cells` on the corresponding axis
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// assert_eq!(Grid::new(3, 3).center(), Cell::new(1, 1));
+    /// assert_eq!(Grid::new(8, 8).center(), Cell::new(4, 4));
+    /// assert_eq!(Grid::new(8, 3).center(), Cell::new(4, 1));
+    /// assert_eq!(Grid::new(3, 8).center(), Cell::new(1, 4));
+    /// assert_eq!(Grid::indented(5, 5, (2, 3)).center(), Cell::new(4, 5));
+    /// assert_eq!(Grid::new(1, 1).center(), Cell::new(0, 0));
+    /// assert!(Grid::new(8, 8).center_cells().contains(&Grid::new(8, 8).center()));
+    /// ```
+    pub fn center(self) -> Cell {
+        self.member(self.width() / 2, self.depth() / 2)
+    }
+
+    /// Clips the segment between `a` and `b` to the `Grid` bounds with Cohen–Sutherland algorithm.
+    /// Returns ends of the clipped segment in the `a` to `b` direction, or `None` if the segment misses the `Grid`
+    ///
+    /// `a` and `b` may be outside the `Grid`, clipped ends are rounded to the nearest `Cell`s
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::indented(5, 5, (5, 5)); // 5x5 grid starting at (5,5)
+    ///
+    /// let (a, b) = (Cell::new(6, 5), Cell::new(8, 9));
+    /// assert_eq!(grid.clip_line(a, b), Some((a, b)));
+    ///
+    /// let (a, b) = (Cell::new(7, 7), Cell::new(20, 7));
+    /// assert_eq!(grid.clip_line(a, b), Some((Cell::new(7, 7), Cell::new(9, 7))));
+    ///
+    /// let (a, b) = (Cell::new(20, 20), Cell::new(0, 0));
+    /// assert_eq!(grid.clip_line(a, b), Some((Cell::new(9, 9), Cell::new(5, 5))));
+    ///
+    /// assert_eq!(grid.clip_line(Cell::new(0, 0), Cell::new(4, 20)), None);
+    /// assert_eq!(grid.clip_line(Cell::new(0, 8), Cell::new(8, 0)), None);
+    /// ```
+    pub fn clip_line(self, a: Cell, b: Cell) -> Option<(Cell, Cell)> {
+        const LEFT: u8 = 0b0001;
+        const RIGHT: u8 = 0b0010;
+        const TOP: u8 = 0b0100;
+        const BOTTOM: u8 = 0b1000;
+        let (min_w, min_d) = (
+            self.start.global_width as f64,
+            self.start.global_depth as f64,
+        );
+        let (max_w, max_d) = (self.end.global_width as f64, self.end.global_depth as f64);
+        let code = |(w, d): (f64, f64)| {
+            let mut code = 0;
+            if w < min_w {
+                code |= LEFT;
+            } else if w > max_w {
+                code |= RIGHT;
+            }
+            if d < min_d {
+                code |= TOP;
+            } else if d > max_d {
+                code |= BOTTOM;
+            }
+            code
+        };
+        let mut a_point = (a.global_width as f64, a.global_depth as f64);
+        let mut b_point = (b.global_width as f64, b.global_depth as f64);
+        let (mut a_code, mut b_code) = (code(a_point), code(b_point));
+        loop {
+            if a_code | b_code == 0 {
+                let cell = |(w, d): (f64, f64)| Cell::new(w.round() as u8, d.round() as u8);
+                return Some((cell(a_point), cell(b_point)));
+            }
+            if a_code & b_code != 0 {
+                return None;
+            }
+            let outside = if a_code != 0 { a_code } else { b_code };
+            let ((w1, d1), (w2, d2)) = (a_point, b_point);
+            let point = if outside & TOP != 0 {
+                (w1 + (w2 - w1) * (min_d - d1) / (d2 - d1), min_d)
+            } else if outside & BOTTOM != 0 {
+                (w1 + (w2 - w1) * (max_d - d1) / (d2 - d1), max_d)
+            } else if outside & LEFT != 0 {
+                (min_w, d1 + (d2 - d1) * (min_w - w1) / (w2 - w1))
+            } else {
+                (max_w, d1 + (d2 - d1) * (max_w - w1) / (w2 - w1))
+            };
+            if outside == a_code {
+                a_point = point;
+                a_code = code(a_point);
+            } else {
+                b_point = point;
+                b_code = code(b_point);
+            }
+        }
+    }
+
+    /// Returns an iterator over every descending diagonal of the `Grid`, where every diagonal is a `Vec<Cell>`
+    ///
+    /// Diagonals go from the top-right corner to the bottom-left corner,
+    /// and cells within the diagonal go from top-left to bottom-right.
+    /// There are `width + depth - 1` diagonals, covering every `Cell` exactly once
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    /// use std::collections::HashSet;
+    ///
+    /// let grid = Grid::new(3, 2);
+    /// let diagonals: Vec<Vec<Cell>> = grid.diagonals().collect();
+    /// assert_eq!(diagonals, vec![
+    ///     vec![Cell::new(2, 0)],
+    ///     vec![Cell::new(1, 0), Cell::new(2, 1)],
+    ///     vec![Cell::new(0, 0), Cell::new(1, 1)],
+    ///     vec![Cell::new(0, 1)],
+    /// ]);
+    ///
+    /// let grid = Grid::indented(7, 4, (2, 3));
+    /// let diagonals: Vec<Vec<Cell>> = grid.diagonals().collect();
+    /// assert_eq!(diagonals.len(), 7 + 4 - 1);
+    /// let covered: HashSet<Cell> = diagonals.iter().flatten().copied().collect();
+    /// assert_eq!(covered.len(), grid.size() as usize);
+    /// assert_eq!(diagonals.iter().map(Vec::len).sum::<usize>(), grid.size() as usize);
+    /// ```
+    pub fn diagonals(self) -> impl Iterator<Item = Vec<Cell>> {
+        let (width, depth) = (self.width() as i16, self.depth() as i16);
+        (1 - width..depth).map(move |offset| {
+            (0..depth)
+                .filter_map(|d| {
+                    let w = d - offset;
+                    (0..width)
+                        .contains(&w)
+                        .then(|| self.member(w as u8, d as u8))
+                })
+                .collect()
+        })
+    }
+
+    /// Returns an iterator over every ascending diagonal of the `Grid`, where every diagonal is a `Vec<Cell>`
+    ///
+    /// Diagonals go from the top-left corner to the bottom-right corner,
+    /// and cells within the diagonal go from bottom-left to top-right.
+    /// There are `width + depth - 1` diagonals, covering every `Cell` exactly once
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    /// use std::collections::HashSet;
+    ///
+    /// let grid = Grid::new(3, 2);
+    /// let diagonals: Vec<Vec<Cell>> = grid.anti_diagonals().collect();
+    /// assert_eq!(diagonals, vec![
+    ///     vec![Cell::new(0, 0)],
+    ///     vec![Cell::new(0, 1), Cell::new(1, 0)],
+    ///     vec![Cell::new(1, 1), Cell::new(2, 0)],
+    ///     vec![Cell::new(2, 1)],
+    /// ]);
+    ///
+    /// let grid = Grid::indented(4, 7, (2, 3));
+    /// let diagonals: Vec<Vec<Cell>> = grid.anti_diagonals().collect();
+    /// assert_eq!(diagonals.len(), 4 + 7 - 1);
+    /// let covered: HashSet<Cell> = diagonals.iter().flatten().copied().collect();
+    /// assert_eq!(covered.len(), grid.size() as usize);
+    /// assert_eq!(diagonals.iter().map(Vec::len).sum::<usize>(), grid.size() as usize);
+    /// ```
+    pub fn anti_diagonals(self) -> impl Iterator<Item = Vec<Cell>> {
+        let (width, depth) = (self.width() as i16, self.depth() as i16);
+        (0..width + depth - 1).map(move |sum| {
+            (0..depth)
+                .rev()
+                .filter_map(|d| {
+                    let w = sum - d;
+                    (0..width)
+                        .contains(&w)
+                        .then(|| self.member(w as u8, d as u8))
+                })
+                .collect()
+        })
+    }
+
+    /// Partitions `Cell`s of the `Grid` into light and dark ones by the checkerboard pattern, in `Grid` order
+    ///
+    /// `Cell` is light if the sum of its `width` and `depth` relative to the `Grid` is even,
+    /// so the `start` of the `Grid` is always light
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(3, 2);
+    /// let (light, dark) = grid.partition_by_parity();
+    /// assert_eq!(light, vec![Cell::new(0, 0), Cell::new(2, 0), Cell::new(1, 1)]);
+    /// assert_eq!(dark, vec![Cell::new(1, 0), Cell::new(0, 1), Cell::new(2, 1)]);
+    ///
+    /// let grid = Grid::indented(5, 3, (1, 4));
+    /// let (light, dark) = grid.partition_by_parity();
+    /// assert_eq!(light.len() + dark.len(), grid.size() as usize);
+    /// assert_eq!(light.len() - dark.len(), 1);
+    /// assert!(light.iter().all(|cell| !dark.contains(cell)));
+    /// ```
+    pub fn partition_by_parity(self) -> (Vec<Cell>, Vec<Cell>) {
+        self.cells()
+            .partition(|cell| (cell.width(self) ^ cell.depth(self)) & 1 == 0)
+    }
+
+    /// Swaps `width` and `depth` axes of the `Grid`, mirroring it across the main diagonal of the global space
+    ///
+    /// Both `start` and `end` are transposed with `Cell::transpose`, so the indent is transposed as well
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::indented(3, 5, (1, 4)); // 3x5 grid starting at (1,4)
+    /// let transposed = grid.transpose();
+    /// assert_eq!(transposed, Grid::indented(5, 3, (4, 1)));
+    /// assert_eq!((transposed.width(), transposed.depth()), (5, 3));
+    /// assert_eq!(transposed.transpose(), grid);
+    /// ```
+    pub fn transpose(self) -> Grid {
+        Grid {
+            start: self.start.transpose(),
+            end: self.end.transpose(),
+        }
+    }
+
+    /// Samples `target_width` x `target_depth` `Cell`s of the `Grid`, evenly spaced on both axes, in `Grid` order
+    ///
+    /// The `Grid` is split into `target_width` x `target_depth` equal blocks,
+    /// and the `Cell` nearest to the center of every block is sampled
+    ///
+    /// # Panics
+    /// Panics if `target_width` or `target_depth` parameters < 1
+    /// Panics if `target_width` or `target_depth` parameters exceed `width` or `depth` of the `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let samples = grid.sample(5, 5);
+    /// assert_eq!(samples.len(), 25);
+    /// assert_eq!(&samples[..5], &[
+    ///     Cell::new(1, 1),
+    ///     Cell::new(3, 1),
+    ///     Cell::new(5, 1),
+    ///     Cell::new(7, 1),
+    ///     Cell::new(9, 1),
+    /// ]);
+    /// assert_eq!(samples[24], Cell::new(9, 9));
+    ///
+    /// assert_eq!(grid.sample(10, 10), grid.cells().collect::<Vec<_>>());
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// grid.sample(11, 5); // panic!
+    /// ```
+    pub fn sample(self, target_width: u8, target_depth: u8) -> Vec<Cell> {
+        if target_width < 1 || target_depth < 1 {
+            panic!("can't sample grid with target width < 1 or target depth < 1")
+        }
+        if target_width > self.width() || target_depth > self.depth() {
+            panic!(
+                "target dimensions exceed grid dimensions! target:({target_width}, {target_depth}), grid:{self}"
+            )
+        }
+        let position = |index: u8, target: u8, size: u8| {
+            ((2 * index as u16 + 1) * size as u16 / (2 * target as u16)) as u8
+        };
+        (0..target_depth)
+            .flat_map(|d| {
+                (0..target_width).map(move |w| {
+                    self.member(
+                        position(w, target_width, self.width()),
+                        position(d, target_depth, self.depth()),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Checks if the `Cell` is within the `Grid`
+    ///
+    /// Mirrors `Cell::within`, but reads naturally when the `Grid` is the subject
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::indented(5, 5, (2, 2));
+    /// assert!(grid.contains_cell(Cell::new(4, 6)));
+    /// assert!(!grid.contains_cell(Cell::new(1, 6)));
+    /// assert_eq!(grid.contains_cell(Cell::new(7, 7)), Cell::new(7, 7).within(grid));
+    /// ```
+    pub fn contains_cell(self, cell: Cell) -> bool {
+        cell.within(self)
+    }
+
+    /// Checks if another `Grid` is within the `Grid`
+    ///
+    /// Mirrors `Grid::within`, but reads naturally when the outer `Grid` is the subject
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let subgrid = Grid::indented(3, 3, (6, 6));
+    /// assert!(grid.contains_grid(subgrid));
+    /// assert!(!subgrid.contains_grid(grid));
+    /// assert_eq!(grid.contains_grid(subgrid), subgrid.within(grid));
+    /// ```
+    pub fn contains_grid(self, other: Grid) -> bool {
+        other.within(self)
+    }
+
+    /// Returns uniformly random `Cell` of the `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    /// use std::collections::HashSet;
+    ///
+    /// let grid = Grid::indented(2, 2, (3, 4));
+    /// let mut rng = rand::rng();
+    /// let drawn: HashSet<_> = (0..200).map(|_| grid.random_cell(&mut rng)).collect();
+    /// assert!(drawn.iter().all(|cell| cell.within(grid)));
+    /// assert_eq!(drawn.len(), 4); // every cell is reachable
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random_cell<R: Rng>(self, rng: &mut R) -> Cell {
+        Cell {
+            global_width: rng.random_range(self.start.global_width..=self.end.global_width),
+            global_depth: rng.random_range(self.start.global_depth..=self.end.global_depth),
+        }
+    }
+
+    /// Returns `n` distinct uniformly random `Cell`s of the `Grid`, in random order
+    ///
+    /// If `n` exceeds the `size` of the `Grid`, result is truncated to every `Cell` of the `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    /// use std::collections::HashSet;
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let mut rng = rand::rng();
+    /// let cells = grid.random_cells(&mut rng, 10);
+    /// assert_eq!(cells.len(), 10);
+    /// assert_eq!(cells.iter().collect::<HashSet<_>>().len(), 10);
+    /// assert!(cells.iter().all(|cell| cell.within(grid)));
+    ///
+    /// let cells = grid.random_cells(&mut rng, 100);
+    /// assert_eq!(cells.len(), grid.size() as usize);
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random_cells<R: Rng>(self, rng: &mut R, n: usize) -> Vec<Cell> {
+        self.cells().choose_multiple(rng, n)
+    }
+
+    /// Returns same-sized `Grid`s immediately above, below, to the left and to the right of the `Grid`,
+    /// each is `Some` only if it is within the `bounds`
+    ///
+    /// # Panics
+    /// Panics if the `Grid` is not within the `bounds`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let bounds = Grid::new(9, 9);
+    /// let chunk = Grid::indented(3, 3, (3, 3));
+    /// assert_eq!(chunk.neighbor_grids(bounds), [
+    ///     Some(Grid::indented(3, 3, (3, 0))),
+    ///     Some(Grid::indented(3, 3, (3, 6))),
+    ///     Some(Grid::indented(3, 3, (0, 3))),
+    ///     Some(Grid::indented(3, 3, (6, 3))),
+    /// ]);
+    ///
+    /// let chunk = Grid::indented(3, 3, (6, 1));
+    /// assert_eq!(chunk.neighbor_grids(bounds), [
+    ///     None,
+    ///     Some(Grid::indented(3, 3, (6, 4))),
+    ///     Some(Grid::indented(3, 3, (3, 1))),
+    ///     None,
+    /// ]);
+    /// ```
+    pub fn neighbor_grids(self, bounds: Grid) -> [Option<Grid>; 4] {
+        self.within_panic(bounds);
+        let (width, depth) = self.dimensions();
+        let shifted = |width_shift: Option<u8>, depth_shift: Option<u8>| {
+            let grid = GridBuilder::new()
+                .width(width)
+                .depth(depth)
+                .indent((width_shift?, depth_shift?))
+                .build()
+                .ok()?;
+            grid.within(bounds).then_some(grid)
+        };
+        let (start_width, start_depth) = self.start.into();
+        [
+            shifted(Some(start_width), start_depth.checked_sub(depth)),
+            shifted(Some(start_width), start_depth.checked_add(depth)),
+            shifted(start_width.checked_sub(width), Some(start_depth)),
+            shifted(start_width.checked_add(width), Some(start_depth)),
+        ]
+    }
+
+    /// Splits the `Grid` into two subgrids at the relative `width`: the first one covers widths `[0, at)`,
+    /// and the second one covers widths `[at, width)`
+    ///
+    /// # Panics
+    /// Panics if `at` parameter is 0, or `at` >= `width` of the `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    /// use std::collections::HashSet;
+    ///
+    /// let grid = Grid::indented(6, 4, (1, 1));
+    /// let (left, right) = grid.split_at_width(2);
+    /// assert_eq!(left, Grid::indented(2, 4, (1, 1)));
+    /// assert_eq!(right, Grid::indented(4, 4, (3, 1)));
+    ///
+    /// let mut cells: HashSet<Cell> = left.cells().collect();
+    /// assert!(right.cells().all(|cell| cells.insert(cell)));
+    /// assert_eq!(cells, grid.cells().collect());
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::new(6, 4);
+    /// grid.split_at_width(6); // panic!
+    /// ```
+    pub fn split_at_width(self, at: u8) -> (Grid, Grid) {
+        if at == 0 || at >= self.width() {
+            panic!("can't split grid with empty part! at:{at}, grid:{self}")
+        }
+        let border = self.start.global_width + at;
+        (
+            Grid {
+                start: self.start,
+                end: Cell {
+                    global_width: border - 1,
+                    global_depth: self.end.global_depth,
+                },
+            },
+            Grid {
+                start: Cell {
+                    global_width: border,
+                    global_depth: self.start.global_depth,
+                },
+                end: self.end,
+            },
+        )
+    }
+
+    /// Splits the `Grid` into two subgrids at the relative `depth`: the first one covers depths `[0, at)`,
+    /// and the second one covers depths `[at, depth)`
+    ///
+    /// # Panics
+    /// Panics if `at` parameter is 0, or `at` >= `depth` of the `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    /// use std::collections::HashSet;
+    ///
+    /// let grid = Grid::indented(4, 6, (1, 1));
+    /// let (top, bottom) = grid.split_at_depth(3);
+    /// assert_eq!(top, Grid::indented(4, 3, (1, 1)));
+    /// assert_eq!(bottom, Grid::indented(4, 3, (1, 4)));
+    ///
+    /// let mut cells: HashSet<Cell> = top.cells().collect();
+    /// assert!(bottom.cells().all(|cell| cells.insert(cell)));
+    /// assert_eq!(cells, grid.cells().collect());
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::new(4, 6);
+    /// grid.split_at_depth(0); // panic!
+    /// ```
+    pub fn split_at_depth(self, at: u8) -> (Grid, Grid) {
+        if at == 0 || at >= self.depth() {
+            panic!("can't split grid with empty part! at:{at}, grid:{self}")
+        }
+        let border = self.start.global_depth + at;
+        (
+            Grid {
+                start: self.start,
+                end: Cell {
+                    global_width: self.end.global_width,
+                    global_depth: border - 1,
+                },
+            },
+            Grid {
+                start: Cell {
+                    global_width: self.start.global_width,
+                    global_depth: border,
+                },
+                end: self.end,
+            },
+        )
+    }
+
+    /// Checks if `inner` `Grid` is within the `Grid` with at least `margin` cells of clearance on every side
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::new(10, 10);
+    /// let inner = Grid::indented(4, 4, (3, 3)); // 3 cells of clearance on every side
+    /// assert!(grid.contains_with_margin(inner, 3));
+    /// assert!(!grid.contains_with_margin(inner, 4));
+    ///
+    /// let inner = Grid::indented(4, 4, (3, 2)); // 2 cells of clearance on the top
+    /// assert!(!grid.contains_with_margin(inner, 3));
+    /// assert!(grid.contains_with_margin(grid, 0));
+    /// ```
+    pub fn contains_with_margin(self, inner: Grid, margin: u8) -> bool {
+        inner.within(self)
+            && inner.start.global_width - self.start.global_width >= margin
+            && inner.start.global_depth - self.start.global_depth >= margin
+            && self.end.global_width - inner.end.global_width >= margin
+            && self.end.global_depth - inner.end.global_depth >= margin
+    }
+
+    /// Returns every `Cell` of the `Grid`, sorted by the distance from the `origin` in ascending order
+    ///
+    /// Distance is calculated with the given `DistanceMetric`, and ties are kept in `Grid` order
+    ///
+    /// # Panics
+    /// Panics if the `origin` is not within the `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, DistanceMetric, Grid};
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let origin = Cell::new(1, 2);
+    /// let cells = grid.cells_by_distance(origin, DistanceMetric::Manhattan);
+    /// assert_eq!(cells.len(), grid.size() as usize);
+    /// assert_eq!(&cells[..5], &[
+    ///     origin,
+    ///     Cell::new(1, 1),
+    ///     Cell::new(0, 2),
+    ///     Cell::new(2, 2),
+    ///     Cell::new(1, 3),
+    /// ]);
+    ///
+    /// let cells = grid.cells_by_distance(origin, DistanceMetric::Chebyshev);
+    /// assert_eq!(cells[0], origin);
+    /// assert!(cells.windows(2).all(|pair| {
+    ///     pair[0].distance(origin, DistanceMetric::Chebyshev)
+    ///         <= pair[1].distance(origin, DistanceMetric::Chebyshev)
+    /// }));
+    /// ```
+    pub fn cells_by_distance(self, origin: Cell, metric: DistanceMetric) -> Vec<Cell> {
+        origin.within_panic(self);
+        let mut cells: Vec<Cell> = self.cells().collect();
+        cells.sort_by_key(|cell| cell.distance(origin, metric));
+        cells
+    }
+
+    /// Creates the smallest near-square `Grid`, starting at (0,0), that can hold at least `count` cells
+    ///
+    /// `width` of the `Grid` is the ceiling of the square root of `count`,
+    /// and `depth` is the minimal one, needed to hold `count` cells
+    ///
+    /// # Panics
+    /// Panics if `count` parameter < 1, or `count` exceeds 255x255
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// assert_eq!(Grid::square_ish_for(16), Grid::new(4, 4));
+    /// assert_eq!(Grid::square_ish_for(10), Grid::new(4, 3));
+    /// assert_eq!(Grid::square_ish_for(13), Grid::new(4, 4));
+    /// assert_eq!(Grid::square_ish_for(1), Grid::new(1, 1));
+    /// assert_eq!(Grid::square_ish_for(255 * 255), Grid::new(255, 255));
+    ///
+    /// for count in 1..200 {
+    ///     assert!(Grid::square_ish_for(count).size() >= count);
+    /// }
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::Grid;
+    ///
+    /// Grid::square_ish_for(255 * 255 + 1); // panic!
+    /// ```
+    pub fn square_ish_for(count: u16) -> Grid {
+        if !(1..=255 * 255).contains(&count) {
+            panic!("can't fit count into grid! count:{count}")
+        }
+        let width = (count as f64).sqrt().ceil() as u16;
+        let depth = count.div_ceil(width);
+        Grid::new(width as u8, depth as u8)
+    }
+
+    /// Encodes current `Grid` into the fixed-size binary representation:
+    /// `[start.global_width, start.global_depth, end.global_width, end.global_depth]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::indented(5, 3, (2, 1));
+    /// assert_eq!(grid.to_bytes(), [2, 1, 6, 3]);
+    /// assert_eq!(Grid::from_bytes(grid.to_bytes()), Ok(grid));
+    /// ```
+    pub const fn to_bytes(self) -> [u8; 4] {
+        let ([start_width, start_depth], [end_width, end_depth]) =
+            (self.start.to_bytes(), self.end.to_bytes());
+        [start_width, start_depth, end_width, end_depth]
+    }
+
+    /// Decodes `Grid` from the fixed-size binary representation:
+    /// `[start.global_width, start.global_depth, end.global_width, end.global_depth]`
+    ///
+    /// # Errors
+    /// Returns `GridError::StartAfterEnd`, if decoded `start` overflows decoded `end` on any axis
+    /// Returns `GridError::DimensionOverflow`, if decoded `Grid` is 256 cells wide or deep
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid, GridError};
+    ///
+    /// let grid = Grid::new(255, 255);
+    /// assert_eq!(Grid::from_bytes(grid.to_bytes()), Ok(grid));
+    ///
+    /// assert_eq!(
+    ///     Grid::from_bytes([4, 0, 2, 9]),
+    ///     Err(GridError::StartAfterEnd { start: Cell::new(4, 0), end: Cell::new(2, 9) })
+    /// );
+    /// assert_eq!(Grid::from_bytes([0, 0, 255, 255]), Err(GridError::DimensionOverflow));
+    /// ```
+    pub const fn from_bytes(bytes: [u8; 4]) -> Result<Grid, GridError> {
+        Grid::checked_from_bounds(
+            Cell::from_bytes([bytes[0], bytes[1]]),
+            Cell::from_bytes([bytes[2], bytes[3]]),
+        )
+    }
+
+    /// Checks if the `Grid` is square: `width` is equal to `depth`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// assert!(Grid::new(5, 5).is_square());
+    /// assert!(!Grid::new(1, 5).is_square());
+    /// assert!(!Grid::new(5, 1).is_square());
+    /// ```
+    pub fn is_square(self) -> bool {
+        self.width() == self.depth()
+    }
+
+    /// Checks if the `Grid` is a single row: `depth` is 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// assert!(Grid::new(5, 1).is_row());
+    /// assert!(!Grid::new(1, 5).is_row());
+    /// assert!(!Grid::new(5, 5).is_row());
+    /// ```
+    pub fn is_row(self) -> bool {
+        self.depth() == 1
+    }
+
+    /// Checks if the `Grid` is a single column: `width` is 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// assert!(Grid::new(1, 5).is_column());
+    /// assert!(!Grid::new(5, 1).is_column());
+    /// assert!(!Grid::new(5, 5).is_column());
+    ///
+    /// // single cell grid is square, row and column at once:
+    /// let single = Grid::new(1, 1);
+    /// assert!(single.is_square() && single.is_row() && single.is_column());
+    /// ```
+    pub fn is_column(self) -> bool {
+        self.width() == 1
+    }
+
+    /// Expands the `Grid` by `by` cells on every side, saturating at the global space bounds
+    /// and at the maximum of 255 cells per axis
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::indented(3, 3, (2, 2));
+    /// assert_eq!(grid.expand(1), Grid::indented(5, 5, (1, 1)));
+    /// assert_eq!(grid.expand(0), grid);
+    ///
+    /// // saturates at the global space bounds:
+    /// assert_eq!(grid.expand(5), Grid::new(10, 10));
+    /// assert_eq!(Grid::indented(3, 3, (252, 0)).expand(10), Grid::from(((242, 0), (255, 12))));
+    ///
+    /// // never grows beyond 255 cells per axis:
+    /// assert_eq!(Grid::new(255, 255).expand(1), Grid::new(255, 255));
+    /// assert_eq!(Grid::indented(3, 3, (100, 100)).expand(200).width(), 255);
+    /// ```
+    pub fn expand(self, by: u8) -> Grid {
+        let start = Cell {
+            global_width: self.start.global_width.saturating_sub(by),
+            global_depth: self.start.global_depth.saturating_sub(by),
+        };
+        Grid {
+            start,
+            end: Cell {
+                global_width: self
+                    .end
+                    .global_width
+                    .saturating_add(by)
+                    .min(start.global_width.saturating_add(u8::MAX - 1)),
+                global_depth: self
+                    .end
+                    .global_depth
+                    .saturating_add(by)
+                    .min(start.global_depth.saturating_add(u8::MAX - 1)),
+            },
+        }
+    }
+
+    /// Shrinks the `Grid` by `by` cells on every side.
+    /// Returns `None`, if nothing is left of the `Grid` after shrinking
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::indented(5, 5, (1, 1));
+    /// assert_eq!(grid.shrink(1), Some(Grid::indented(3, 3, (2, 2))));
+    /// assert_eq!(grid.shrink(2), Some(Grid::indented(1, 1, (3, 3))));
+    /// assert_eq!(grid.shrink(3), None);
+    /// assert_eq!(grid.shrink(1).map(|grid| grid.expand(1)), Some(grid));
+    ///
+    /// assert_eq!(Grid::new(6, 2).shrink(1), None);
+    /// ```
+    pub fn shrink(self, by: u8) -> Option<Grid> {
+        let start = Cell {
+            global_width: self.start.global_width.checked_add(by)?,
+            global_depth: self.start.global_depth.checked_add(by)?,
+        };
+        let end = Cell {
+            global_width: self.end.global_width.checked_sub(by)?,
+            global_depth: self.end.global_depth.checked_sub(by)?,
+        };
+        Grid::checked_from_bounds(start, end).ok()
+    }
+
+    /// Returns every `Cell` of the `Grid`, that is not within another `Grid`, in `Grid` order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// // # # #
+    /// // # . . .
+    /// // # . . .
+    /// //   . . .
+    /// let old = Grid::new(3, 3);
+    /// let new = Grid::indented(3, 3, (1, 1));
+    /// assert_eq!(old.difference(new), vec![
+    ///     Cell::new(0, 0),
+    ///     Cell::new(1, 0),
+    ///     Cell::new(2, 0),
+    ///     Cell::new(0, 1),
+    ///     Cell::new(0, 2),
+    /// ]);
+    ///
+    /// let far = Grid::indented(3, 3, (10, 10));
+    /// assert_eq!(old.difference(far), old.cells().collect::<Vec<Cell>>());
+    /// assert!(old.difference(Grid::new(5, 5)).is_empty());
+    /// assert!(old.difference(old).is_empty());
+    /// ```
+    pub fn difference(self, other: Grid) -> Vec<Cell> {
+        self.cells().filter(|cell| !cell.within(other)).collect()
+    }
+
+    /// Scales the `Grid` dimensions by `factor`, keeping the same `start`
+    ///
+    /// # Panics
+    /// Panics if `factor` is 0, or the scaled `Grid` exceeds the global space bounds on any axis
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// assert_eq!(Grid::new(2, 2).scale(3), Grid::new(6, 6));
+    /// assert_eq!(Grid::indented(4, 2, (1, 5)).scale(2), Grid::indented(8, 4, (1, 5)));
+    /// assert_eq!(Grid::new(5, 5).scale(51), Grid::new(255, 255));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::Grid;
+    ///
+    /// Grid::indented(2, 2, (1, 1)).scale(128); // panic!
+    /// ```
+    pub fn scale(self, factor: u8) -> Grid {
+        let scaled = |start: u8, length: u8| {
+            length
+                .checked_mul(factor)
+                .filter(|&length| length > 0)
+                .and_then(|length| start.checked_add(length - 1))
+        };
+        match (
+            scaled(self.start.global_width, self.width()),
+            scaled(self.start.global_depth, self.depth()),
+        ) {
+            (Some(global_width), Some(global_depth)) => Grid {
+                start: self.start,
+                end: Cell {
+                    global_width,
+                    global_depth,
+                },
+            },
+            _ => panic!("can't scale grid! factor:{factor}, grid:{self}"),
+        }
+    }
+}
+
+impl From<(Cell, Cell)> for Grid {
+    /// implements constructor for `Grid` from (Cell, Cell)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let cells = (Cell::new(2, 2), Cell::new(5, 5));
+    /// let grid = Grid::from(cells);
+    /// assert_eq!((cells.0, cells.1), (grid.start(), grid.end()));
     /// ```
     fn from(value: (Cell, Cell)) -> Self {
         let (start, end) = value;
-        if start.global_width > end.global_width || start.global_depth > end.global_depth {
-            panic!("start cell overflows end cell! start:{start}, end:{end}")
+        Grid::checked_from_bounds(start, end).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<(Cell, Cell)> for Grid {
+    /// implements conversion from `Grid` into (Cell, Cell)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let cells: (Cell, Cell) = grid.into();
+    /// assert_eq!((cells.0, cells.1), (grid.start(), grid.end()));
+    /// ```
+    fn into(self) -> (Cell, Cell) {
+        (self.start, self.end)
+    }
+}
+
+impl From<((u8, u8), (u8, u8))> for Grid {
+    /// implements constructor for `Grid` from ((u8, u8), (u8, u8))
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let vals = ((2, 2), (5, 5));
+    /// let grid = Grid::from(vals);
+    /// assert_eq!((Cell::from(vals.0), Cell::from(vals.1)), (grid.start(), grid.end()));
+    /// ```
+    fn from(value: ((u8, u8), (u8, u8))) -> Self {
+        let (start, end): (Cell, Cell) = (value.0.into(), value.1.into());
+        Grid::checked_from_bounds(start, end).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<((u8, u8), (u8, u8))> for Grid {
+    /// implements conversion from `Grid` into ((u8, u8), (u8, u8))
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let vals: ((u8, u8), (u8, u8)) = grid.into();
+    /// assert_eq!((Cell::from(vals.0), Cell::from(vals.1)), (grid.start(), grid.end()));
+    /// ```
+    fn into(self) -> ((u8, u8), (u8, u8)) {
+        (self.start.into(), self.end.into())
+    }
+}
+
+impl fmt::Display for Grid {
+    /// implements display for `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::new(5, 6);
+    /// assert_eq!(format!("{grid}"), "[(0, 0):(4, 5)]");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{start}:{end}]", start = self.start, end = self.end)
+    }
+}
+
+impl GridBuilder {
+    /// Creates new `GridBuilder` with `width` and `depth` equal to 0, and `indent` equal to (0, 0)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::GridBuilder;
+    ///
+    /// let builder = GridBuilder::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets `width` of the `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::GridBuilder;
+    ///
+    /// let grid = GridBuilder::new().width(8).depth(1).build().unwrap();
+    /// assert_eq!(grid.width(), 8);
+    /// ```
+    pub fn width(self, width: u8) -> Self {
+        Self { width, ..self }
+    }
+
+    /// Sets `depth` of the `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::GridBuilder;
+    ///
+    /// let grid = GridBuilder::new().width(1).depth(8).build().unwrap();
+    /// assert_eq!(grid.depth(), 8);
+    /// ```
+    pub fn depth(self, depth: u8) -> Self {
+        Self { depth, ..self }
+    }
+
+    /// Sets `indent` of the `Grid` start
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, GridBuilder};
+    ///
+    /// let grid = GridBuilder::new().width(3).depth(3).indent((2, 4)).build().unwrap();
+    /// assert_eq!(grid.start(), Cell::new(2, 4));
+    /// ```
+    pub fn indent(self, indent: (u8, u8)) -> Self {
+        Self { indent, ..self }
+    }
+
+    /// Builds new `Grid` from the specified parameters
+    ///
+    /// # Errors
+    /// Returns `GridError::EmptyDimension`, if `width` or `depth` < 1
+    /// Returns `GridError::DimensionOverflow`, if the `Grid` end exceeds `u8` global position bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Grid, GridBuilder, GridError};
+    ///
+    /// let grid = GridBuilder::new().width(10).depth(10).build();
+    /// assert_eq!(grid, Ok(Grid::new(10, 10)));
+    ///
+    /// let grid = GridBuilder::new().width(10).depth(10).indent((3, 3)).build();
+    /// assert_eq!(grid, Ok(Grid::indented(10, 10, (3, 3))));
+    ///
+    /// let grid = GridBuilder::new().width(10).build();
+    /// assert_eq!(grid, Err(GridError::EmptyDimension));
+    ///
+    /// let grid = GridBuilder::new().width(10).depth(10).indent((250, 0)).build();
+    /// assert_eq!(grid, Err(GridError::DimensionOverflow));
+    /// ```
+    pub fn build(self) -> Result<Grid, GridError> {
+        Grid::try_indented(self.width, self.depth, self.indent)
+    }
+}
+
+impl TorusGrid {
+    /// Creates new `TorusGrid` with specified `width: u8` and `depth: u8`, starting at (0,0)
+    ///
+    /// # Panics
+    /// Panics if `width` or `depth` parameters < 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Grid, TorusGrid};
+    ///
+    /// let torus = TorusGrid::new(5, 5);
+    /// assert_eq!(torus.grid(), Grid::new(5, 5));
+    /// ```
+    pub fn new(width: u8, depth: u8) -> Self {
+        Self {
+            grid: Grid::new(width, depth),
+        }
+    }
+
+    /// Returns the inner `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Grid, TorusGrid};
+    ///
+    /// let grid = Grid::indented(5, 5, (2, 2));
+    /// let torus = TorusGrid::from(grid);
+    /// assert_eq!(torus.grid(), grid);
+    /// ```
+    pub fn grid(self) -> Grid {
+        self.grid
+    }
+
+    /// Returns new `Cell` by `width: u8` and `depth: u8` relative to the inner `Grid`,
+    /// wrapping around the `Grid` bounds on both axes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, TorusGrid};
+    ///
+    /// let torus = TorusGrid::new(5, 5);
+    /// assert_eq!(torus.member(2, 3), Cell::new(2, 3));
+    /// assert_eq!(torus.member(7, 13), Cell::new(2, 3));
+    /// ```
+    pub fn member(self, width: u8, depth: u8) -> Cell {
+        let start = self.grid.start;
+        self.down(self.right(start, width), depth)
+    }
+
+    /// Moves the `Cell` up by `step`, wrapping around the inner `Grid` bounds.
+    /// Returns new `Cell` and `bool`, signaling that the move wrapped around the `Grid`
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the inner `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, TorusGrid};
+    ///
+    /// let torus = TorusGrid::new(5, 5);
+    /// assert_eq!(torus.overflowing_up(Cell::new(2, 1), 2), (Cell::new(2, 4), true));
+    /// assert_eq!(torus.overflowing_up(Cell::new(2, 3), 2), (Cell::new(2, 1), false));
+    /// ```
+    pub fn overflowing_up(self, cell: Cell, step: u8) -> (Cell, bool) {
+        cell.overflowing_up(self.grid, step)
+    }
+
+    /// Moves the `Cell` up by `step`, wrapping around the inner `Grid` bounds
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the inner `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, TorusGrid};
+    ///
+    /// let torus = TorusGrid::new(5, 5);
+    /// assert_eq!(torus.up(Cell::new(2, 1), 2), Cell::new(2, 4));
+    /// ```
+    pub fn up(self, cell: Cell, step: u8) -> Cell {
+        self.overflowing_up(cell, step).0
+    }
+
+    /// Moves the `Cell` down by `step`, wrapping around the inner `Grid` bounds.
+    /// Returns new `Cell` and `bool`, signaling that the move wrapped around the `Grid`
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the inner `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, TorusGrid};
+    ///
+    /// let torus = TorusGrid::new(5, 5);
+    /// assert_eq!(torus.overflowing_down(Cell::new(2, 4), 1), (Cell::new(2, 0), true));
+    /// assert_eq!(torus.overflowing_down(Cell::new(2, 1), 2), (Cell::new(2, 3), false));
+    /// ```
+    pub fn overflowing_down(self, cell: Cell, step: u8) -> (Cell, bool) {
+        cell.overflowing_down(self.grid, step)
+    }
+
+    /// Moves the `Cell` down by `step`, wrapping around the inner `Grid` bounds
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the inner `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, TorusGrid};
+    ///
+    /// let torus = TorusGrid::new(5, 5);
+    /// assert_eq!(torus.down(Cell::new(2, 4), 1), Cell::new(2, 0));
+    /// ```
+    pub fn down(self, cell: Cell, step: u8) -> Cell {
+        self.overflowing_down(cell, step).0
+    }
+
+    /// Moves the `Cell` left by `step`, wrapping around the inner `Grid` bounds.
+    /// Returns new `Cell` and `bool`, signaling that the move wrapped around the `Grid`
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the inner `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, TorusGrid};
+    ///
+    /// let torus = TorusGrid::new(5, 5);
+    /// assert_eq!(torus.overflowing_left(Cell::new(0, 2), 1), (Cell::new(4, 2), true));
+    /// assert_eq!(torus.overflowing_left(Cell::new(3, 2), 2), (Cell::new(1, 2), false));
+    /// ```
+    pub fn overflowing_left(self, cell: Cell, step: u8) -> (Cell, bool) {
+        cell.overflowing_left(self.grid, step)
+    }
+
+    /// Moves the `Cell` left by `step`, wrapping around the inner `Grid` bounds
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the inner `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, TorusGrid};
+    ///
+    /// let torus = TorusGrid::new(5, 5);
+    /// assert_eq!(torus.left(Cell::new(0, 2), 1), Cell::new(4, 2));
+    /// ```
+    pub fn left(self, cell: Cell, step: u8) -> Cell {
+        self.overflowing_left(cell, step).0
+    }
+
+    /// Moves the `Cell` right by `step`, wrapping around the inner `Grid` bounds.
+    /// Returns new `Cell` and `bool`, signaling that the move wrapped around the `Grid`
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the inner `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, TorusGrid};
+    ///
+    /// let torus = TorusGrid::new(5, 5);
+    /// assert_eq!(torus.overflowing_right(Cell::new(3, 2), 4), (Cell::new(2, 2), true));
+    /// assert_eq!(torus.overflowing_right(Cell::new(1, 2), 2), (Cell::new(3, 2), false));
+    /// ```
+    pub fn overflowing_right(self, cell: Cell, step: u8) -> (Cell, bool) {
+        cell.overflowing_right(self.grid, step)
+    }
+
+    /// Moves the `Cell` right by `step`, wrapping around the inner `Grid` bounds
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the inner `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, TorusGrid};
+    ///
+    /// let torus = TorusGrid::new(5, 5);
+    /// assert_eq!(torus.right(Cell::new(3, 2), 4), Cell::new(2, 2));
+    /// ```
+    pub fn right(self, cell: Cell, step: u8) -> Cell {
+        self.overflowing_right(cell, step).0
+    }
+
+    /// Returns four neighbors of the `Cell`, wrapping around the inner `Grid` bounds
+    ///
+    /// Neighbors go in the following order: `up`, `down`, `left`, `right`.
+    /// Unlike `Cell::neighbors`, this method always returns four `Cell`s,
+    /// which may repeat if the `Grid` is narrower than 3 cells on any axis
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the inner `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, TorusGrid};
+    ///
+    /// let torus = TorusGrid::new(5, 5);
+    /// assert_eq!(torus.neighbors(Cell::new(0, 0)), vec![
+    ///     Cell::new(0, 4),
+    ///     Cell::new(0, 1),
+    ///     Cell::new(4, 0),
+    ///     Cell::new(1, 0),
+    /// ]);
+    ///
+    /// let torus = TorusGrid::new(1, 1);
+    /// assert_eq!(torus.neighbors(Cell::new(0, 0)), vec![Cell::new(0, 0); 4]);
+    /// ```
+    pub fn neighbors(self, cell: Cell) -> Vec<Cell> {
+        vec![
+            self.up(cell, 1),
+            self.down(cell, 1),
+            self.left(cell, 1),
+            self.right(cell, 1),
+        ]
+    }
+}
+
+impl From<Grid> for TorusGrid {
+    /// Creates new `TorusGrid`, wrapping around the given `Grid`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Grid, TorusGrid};
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let torus = TorusGrid::from(grid);
+    /// assert_eq!(torus.grid(), grid);
+    /// ```
+    fn from(value: Grid) -> Self {
+        Self { grid: value }
+    }
+}
+
+impl From<Grid> for Cells {
+    /// Creates new iterator over every `Cell` on the `Grid`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Grid, Cells};
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let cells = Cells::from(grid);
+    /// ```
+    fn from(grid: Grid) -> Self {
+        Self {
+            grid,
+            current: grid.start,
+            consumed: false,
+        }
+    }
+}
+
+impl From<Grid> for Columns {
+    /// Creates new iterator over every column on the `Grid`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Grid, Columns};
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let columns = Columns::from(grid);
+    /// ```
+    fn from(grid: Grid) -> Self {
+        Self {
+            grid,
+            current: Grid {
+                start: grid.start,
+                end: grid.start.project_down(grid),
+            },
+            consumed: false,
+        }
+    }
+}
+
+impl From<Grid> for Rows {
+    /// Creates new iterator over every row on the `Grid`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Grid, Rows};
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let rows = Rows::from(grid);
+    /// ```
+    fn from(grid: Grid) -> Self {
+        Self {
+            grid,
+            current: Grid {
+                start: grid.start,
+                end: grid.start.project_right(grid),
+            },
+            consumed: false,
+        }
+    }
+}
+
+impl From<Grid> for Ring {
+    /// Creates new iterator over every `Cell` on the perimeter of the `Grid`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Grid, Ring};
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let ring = Ring::from(grid);
+    /// ```
+    fn from(grid: Grid) -> Self {
+        Self {
+            grid,
+            current: grid.start,
+            consumed: false,
+        }
+    }
+}
+
+impl From<Grid> for Spiral {
+    /// Creates new iterator over every `Cell` on the `Grid`, going in the inward clockwise spiral
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Grid, Spiral};
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let spiral = Spiral::from(grid);
+    /// ```
+    fn from(grid: Grid) -> Self {
+        Self {
+            layer: grid,
+            ring: Ring::from(grid),
+            consumed: false,
+        }
+    }
+}
+
+impl From<Grid> for BorderCells {
+    /// Creates new iterator over every `Cell` on the perimeter of the `Grid`, paired with its `Edge`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{BorderCells, Grid};
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let border = BorderCells::from(grid);
+    /// ```
+    fn from(grid: Grid) -> Self {
+        Self {
+            grid,
+            ring: Ring::from(grid),
+        }
+    }
+}
+
+impl Rows {
+    /// Returns the next row without consuming it, or `None` if the iterator is consumed
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::new(3, 2);
+    /// let mut rows = grid.rows();
+    /// assert_eq!(rows.peek(), Some(Grid::from(((0, 0), (2, 0)))));
+    /// assert_eq!(rows.peek(), rows.next());
+    /// assert_eq!(rows.peek(), rows.next());
+    /// assert_eq!(rows.peek(), None);
+    /// assert_eq!(rows.next(), None);
+    /// ```
+    pub fn peek(&self) -> Option<Grid> {
+        (!self.consumed).then_some(self.current)
+    }
+
+    /// Returns an iterator over every contiguous group of `n` consecutive rows, sliding by one row.
+    /// Iteration stops when fewer than `n` rows remain, so nothing is yielded if there are fewer than `n` rows at all
+    ///
+    /// # Panics
+    /// Panics if `n` < 1
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::new(3, 5);
+    /// let windows: Vec<Vec<Grid>> = grid.rows().windows(3).collect();
+    /// assert_eq!(windows.len(), 3);
+    /// assert!(windows.iter().all(|window| window.len() == 3));
+    /// assert!(windows.iter().flatten().all(|row| (row.width(), row.depth()) == (3, 1)));
+    /// assert_eq!(windows[1][0], grid.rows().nth(1).unwrap());
+    ///
+    /// assert_eq!(grid.rows().windows(5).count(), 1);
+    /// assert_eq!(grid.rows().windows(6).count(), 0);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::new(3, 5);
+    /// grid.rows().windows(0); // panic!
+    /// ```
+    pub fn windows(self, n: u8) -> impl Iterator<Item = Vec<Grid>> {
+        if n < 1 {
+            panic!("can't make windows with n < 1")
+        }
+        let rows: Vec<Grid> = self.collect();
+        let count = (rows.len() + 1).saturating_sub(n as usize);
+        (0..count).map(move |index| rows[index..index + n as usize].to_vec())
+    }
+}
+
+impl Columns {
+    /// Returns the next column without consuming it, or `None` if the iterator is consumed
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::new(2, 3);
+    /// let mut columns = grid.columns();
+    /// assert_eq!(columns.peek(), Some(Grid::from(((0, 0), (0, 2)))));
+    /// assert_eq!(columns.peek(), columns.next());
+    /// assert_eq!(columns.peek(), columns.next());
+    /// assert_eq!(columns.peek(), None);
+    /// assert_eq!(columns.next(), None);
+    /// ```
+    pub fn peek(&self) -> Option<Grid> {
+        (!self.consumed).then_some(self.current)
+    }
+
+    /// Returns an iterator over every contiguous group of `n` consecutive columns, sliding by one column.
+    /// Iteration stops when fewer than `n` columns remain, so nothing is yielded if there are fewer than `n` columns at all
+    ///
+    /// # Panics
+    /// Panics if `n` < 1
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::new(5, 3);
+    /// let windows: Vec<Vec<Grid>> = grid.columns().windows(3).collect();
+    /// assert_eq!(windows.len(), 3);
+    /// assert!(windows.iter().all(|window| window.len() == 3));
+    /// assert!(windows.iter().flatten().all(|column| (column.width(), column.depth()) == (1, 3)));
+    /// assert_eq!(windows[1][0], grid.columns().nth(1).unwrap());
+    ///
+    /// assert_eq!(grid.columns().windows(5).count(), 1);
+    /// assert_eq!(grid.columns().windows(6).count(), 0);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::new(5, 3);
+    /// grid.columns().windows(0); // panic!
+    /// ```
+    pub fn windows(self, n: u8) -> impl Iterator<Item = Vec<Grid>> {
+        if n < 1 {
+            panic!("can't make windows with n < 1")
+        }
+        let columns: Vec<Grid> = self.collect();
+        let count = (columns.len() + 1).saturating_sub(n as usize);
+        (0..count).map(move |index| columns[index..index + n as usize].to_vec())
+    }
+}
+
+impl Cells {
+    /// Returns the next `Cell` without consuming it, or `None` if the iterator is consumed
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let grid = Grid::new(2, 2);
+    /// let mut cells = grid.cells();
+    /// assert_eq!(cells.current(), Some(Cell::new(0, 0)));
+    /// assert_eq!(cells.current(), cells.next());
+    /// assert_eq!(cells.current(), Some(Cell::new(1, 0)));
+    /// while let Some(current) = cells.current() {
+    ///     assert_eq!(Some(current), cells.next());
+    /// }
+    /// assert_eq!(cells.next(), None);
+    /// ```
+    pub fn current(&self) -> Option<Cell> {
+        (!self.consumed).then_some(self.current)
+    }
+
+    /// Returns the number of `Cell`s remaining in the iterator
+    ///
+    /// Same as `len` from the `ExactSizeIterator`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::new(3, 2);
+    /// let mut cells = grid.cells();
+    /// assert_eq!(cells.remaining(), 6);
+    /// cells.next();
+    /// assert_eq!(cells.remaining(), 5);
+    /// cells.by_ref().for_each(drop);
+    /// assert_eq!(cells.remaining(), 0);
+    /// ```
+    pub fn remaining(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Iterator for Cells {
+    type Item = Cell;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.consumed {
+            return None;
+        }
+        if self.current == self.grid.end {
+            self.consumed = true;
+            return Some(self.current);
+        }
+        let previous = self.current;
+        match self.current.overflowing_right(self.grid, 1) {
+            (next, true) => self.current = next.wrapping_down(self.grid, 1),
+            (next, false) => self.current = next,
+        }
+        Some(previous)
+    }
+
+    /// Returns the exact number of `Cell`s remaining in the iterator
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::Grid;
+    ///
+    /// let grid = Grid::new(4, 3);
+    /// let mut cells = grid.cells();
+    /// assert_eq!(cells.len(), grid.size() as usize);
+    /// assert_eq!(cells.size_hint(), (12, Some(12)));
+    ///
+    /// cells.next();
+    /// cells.next();
+    /// cells.next();
+    /// cells.next();
+    /// cells.next();
+    /// assert_eq!(cells.len(), 7);
+    ///
+    /// cells.by_ref().for_each(drop);
+    /// assert_eq!(cells.len(), 0);
+    /// ```
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.consumed {
+            return (0, Some(0));
+        }
+        let remaining = self.current.depth_gap(self.grid) as usize * self.grid.width() as usize
+            + self.current.width_gap(self.grid) as usize
+            + 1;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Cells {}
+
+impl Iterator for Columns {
+    type Item = Grid;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.consumed {
+            return None;
+        }
+        if self.current.end == self.grid.end {
+            self.consumed = true;
+            return Some(self.current);
+        }
+        let previous = self.current;
+        self.current = Grid {
+            start: self.current.start.saturating_right(self.grid, 1),
+            end: self.current.end.saturating_right(self.grid, 1),
+        };
+        Some(previous)
+    }
+}
+
+impl Iterator for Rows {
+    type Item = Grid;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.consumed {
+            return None;
+        }
+        if self.current.end == self.grid.end {
+            self.consumed = true;
+            return Some(self.current);
+        }
+        let previous = self.current;
+        self.current = Grid {
+            start: self.current.start.saturating_down(self.grid, 1),
+            end: self.current.end.saturating_down(self.grid, 1),
+        };
+        Some(previous)
+    }
+}
+
+impl Iterator for Ring {
+    type Item = Cell;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.consumed {
+            return None;
+        }
+        let (grid, previous) = (self.grid, self.current);
+        let (start, end) = (grid.start, grid.end);
+        let next = if previous.global_depth == start.global_depth
+            && previous.global_width < end.global_width
+        {
+            previous.strict_right(grid, 1)
+        } else if previous.global_width == end.global_width
+            && previous.global_depth < end.global_depth
+        {
+            previous.strict_down(grid, 1)
+        } else if grid.depth() > 1
+            && previous.global_depth == end.global_depth
+            && previous.global_width > start.global_width
+        {
+            previous.strict_left(grid, 1)
+        } else if grid.width() > 1
+            && previous.global_width == start.global_width
+            && previous.global_depth > start.global_depth
+        {
+            previous.strict_up(grid, 1)
+        } else {
+            start
+        };
+        if next == start {
+            self.consumed = true;
+        }
+        self.current = next;
+        Some(previous)
+    }
+}
+
+impl Iterator for Spiral {
+    type Item = Cell;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.consumed {
+            return None;
+        }
+        if let Some(cell) = self.ring.next() {
+            return Some(cell);
+        }
+        if self.layer.width() <= 2 || self.layer.depth() <= 2 {
+            self.consumed = true;
+            return None;
+        }
+        self.layer = Grid {
+            start: self
+                .layer
+                .start
+                .strict_right(self.layer, 1)
+                .strict_down(self.layer, 1),
+            end: self
+                .layer
+                .end
+                .strict_left(self.layer, 1)
+                .strict_up(self.layer, 1),
+        };
+        self.ring = Ring::from(self.layer);
+        self.ring.next()
+    }
+}
+
+impl Iterator for LineIter {
+    type Item = Cell;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.consumed {
+            return None;
+        }
+        if self.current == self.end {
+            self.consumed = true;
+            return Some(self.current);
+        }
+        let previous = self.current;
+        let doubled = 2 * self.error;
+        if doubled >= self.depth_delta {
+            self.error += self.depth_delta;
+            self.current.global_width = (self.current.global_width as i16 + self.width_step) as u8;
+        }
+        if doubled <= self.width_delta {
+            self.error += self.width_delta;
+            self.current.global_depth = (self.current.global_depth as i16 + self.depth_step) as u8;
+        }
+        Some(previous)
+    }
+}
+
+impl Iterator for BorderCells {
+    type Item = (Cell, Edge);
+    fn next(&mut self) -> Option<Self::Item> {
+        let cell = self.ring.next()?;
+        let (start, end) = (self.grid.start, self.grid.end);
+        let top = cell.global_depth == start.global_depth;
+        let bottom = !top && cell.global_depth == end.global_depth;
+        let left = cell.global_width == start.global_width;
+        let right = !left && cell.global_width == end.global_width;
+        let edge = match (top, bottom, left, right) {
+            (true, _, true, _) => Edge::Corner(Corner::TopLeft),
+            (true, _, _, true) => Edge::Corner(Corner::TopRight),
+            (_, true, true, _) => Edge::Corner(Corner::BottomLeft),
+            (_, true, _, true) => Edge::Corner(Corner::BottomRight),
+            (true, ..) => Edge::Top,
+            (_, true, ..) => Edge::Bottom,
+            (.., true, _) => Edge::Left,
+            _ => Edge::Right,
+        };
+        Some((cell, edge))
+    }
+}
+
+impl<V> From<Grid> for GridMap<V> {
+    /// Creates new `GridMap` from the given `Grid` with empty `HashMap<Cell, V>`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Grid, GridMap};
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let map: GridMap<char> = GridMap::from(grid);
+    /// ```
+    fn from(grid: Grid) -> Self {
+        Self {
+            grid,
+            hashmap: HashMap::new(),
+        }
+    }
+}
+
+impl<V> From<(Grid, HashMap<Cell, V>)> for GridMap<V> {
+    /// Creates new `GridMap` from the existing `HashMap<Cell, V>` and the given `Grid`
+    ///
+    /// # Panics
+    /// Panics if the given `HashMap<Cell, V>` contains `Cell`s that are not within the given `Grid`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid, GridMap};
+    /// use std::collections::HashMap;
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let mut hashmap: HashMap<Cell, char> = HashMap::new();
+    /// let target = Cell::new(1, 2);
+    /// hashmap.insert(target, '#');
+    /// let map: GridMap<char> = GridMap::from((grid, hashmap));
+    /// assert_eq!(map.get(&target), Some(&'#'));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::{Cell, Grid, GridMap};
+    /// use std::collections::HashMap;
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let mut hashmap: HashMap<Cell, char> = HashMap::new();
+    /// let target = Cell::new(6, 2);
+    /// hashmap.insert(target, '#');
+    /// let map: GridMap<char> = GridMap::from((grid, hashmap)); // panic!
+    /// ```
+    fn from(data: (Grid, HashMap<Cell, V>)) -> Self {
+        data.1.keys().for_each(|cell| cell.within_panic(data.0));
+        Self {
+            grid: data.0,
+            hashmap: data.1,
+        }
+    }
+}
+
+impl<V> FromIterator<(Cell, V)> for GridMap<V> {
+    /// Collects `(Cell, V)` pairs into the `GridMap`, where the inner `Grid` is the bounding box of all `Cell`s
+    ///
+    /// # Panics
+    /// Panics if the iterator is empty, because the `Grid` can't be empty
+    /// Panics if the `Cell`s span 256 cells on any axis, because such `Grid` does not fit into the `u8` global positions
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid, GridMap};
+    ///
+    /// let map: GridMap<u8> = Grid::new(4, 4)
+    ///     .cells()
+    ///     .filter(|cell| cell.global_width() == cell.global_depth())
+    ///     .map(|cell| (cell, cell.global_width()))
+    ///     .collect();
+    /// assert_eq!(map.grid(), Grid::new(4, 4));
+    /// assert_eq!(map.len(), 4);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::{Cell, GridMap};
+    ///
+    /// let map: GridMap<u8> = [(Cell::new(3, 0), 1), (Cell::new(3, 255), 2)].into_iter().collect(); // panic!
+    /// ```
+    fn from_iter<I: IntoIterator<Item = (Cell, V)>>(iter: I) -> Self {
+        GridMap::from_cells(iter)
+    }
+}
+
+impl<V> GridMap<V> {
+    /// Creates new `GridMap` with `Grid` of specified sizes, and with empty `HashMap<Cell, V>`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Grid, GridMap};
+    ///
+    /// let map: GridMap<char> = GridMap::new(5, 5);
+    ///
+    /// assert_eq!(map.grid(), Grid::new(5, 5));
+    /// ```
+    pub fn new(width: u8, depth: u8) -> Self {
+        Self {
+            grid: Grid::new(width, depth),
+            hashmap: HashMap::new(),
+        }
+    }
+
+    /// Shadows `insert` method from the `HashMap`, and reimplements it
+    /// so it checks first if the key (`Cell`) is within the `Grid`, and then inserts it into the `HashMap`.
+    /// For the non-panicking version, see `try_insert`
+    ///
+    /// # Panics
+    /// Panics, if the key (`Cell`) is not within the inner `Grid`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Grid, GridMap};
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let mut map: GridMap<char> = GridMap::from(grid);
+    /// map.insert(map.grid().start(), '#');
+    /// map.insert(map.grid().end(), '@');
+    /// assert_eq!(map.len(), 2);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::{Cell, Grid, GridMap};
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let cell = Cell::new(6, 6);
+    /// let mut map: GridMap<char> = GridMap::from(grid);
+    /// map.insert(cell, '#'); // panic!
+    /// ```
+    pub fn insert(&mut self, cell: Cell, value: V) -> Option<V> {
+        self.try_insert(cell, value)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Inserts new object into the `GridMap`, if the key (`Cell`) is within the inner `Grid`.
+    /// Returns the displaced value, if there was one
+    ///
+    /// # Errors
+    /// Returns `GridError::CellOutOfGrid`, if the key (`Cell`) is not within the inner `Grid`,
+    /// leaving the `GridMap` unchanged
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid, GridError, GridMap};
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let mut map: GridMap<char> = GridMap::from(grid);
+    /// assert_eq!(map.try_insert(Cell::new(2, 2), '#'), Ok(None));
+    /// assert_eq!(map.try_insert(Cell::new(2, 2), '@'), Ok(Some('#')));
+    ///
+    /// let cell = Cell::new(6, 6);
+    /// assert_eq!(map.try_insert(cell, '#'), Err(GridError::CellOutOfGrid { cell, grid }));
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn try_insert(&mut self, cell: Cell, value: V) -> Result<Option<V>, GridError> {
+        cell.checked_within(self.grid)?;
+        Ok(self.hashmap.insert(cell, value))
+    }
+
+    /// Inserts new object only if the `Cell` is not occupied.
+    /// Returns `true` if inserted, and `false` if not
+    ///
+    /// # Panics
+    /// Panics, if the key (`Cell`) is not within the inner `Grid`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Grid, GridMap};
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let mut map: GridMap<char> = GridMap::from(grid);
+    /// assert!(map.vacant_insert(map.grid().start(), '#'));
+    /// assert!(!map.vacant_insert(map.grid().start(), '@'));
+    /// assert_eq!(map.get(&map.grid().start()), Some(&'#'));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid_math::{Cell, Grid, GridMap};
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let cell = Cell::new(6, 6);
+    /// let mut map: GridMap<char> = GridMap::from(grid);
+    /// map.vacant_insert(cell, '#'); // panic!
+    /// ```
+    pub fn vacant_insert(&mut self, cell: Cell, value: V) -> bool {
+        cell.within_panic(self.grid);
+        if self.vacant(cell) {
+            self.hashmap.insert(cell, value);
+            true
+        } else {
+            false
         }
-        Self { start, end }
     }
-}
 
-#[allow(clippy::from_over_into)]
-impl Into<(Cell, Cell)> for Grid {
-    /// implements conversion from `Grid` into (Cell, Cell)
+    /// Returns the inner `Grid`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Grid, GridMap};
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let map: GridMap<char> = GridMap::from(grid);
+    ///
+    /// assert_eq!(grid, map.grid());
+    /// ```
+    pub fn grid(&self) -> Grid {
+        self.grid
+    }
+
+    /// Checks if the `Cell` is occupied. This is an alias for `contains_key` method
+    ///
+    /// # Panics
+    /// Panics, if the given `Cell` is not within the inner `Grid`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid, GridMap};
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let cell = Cell::new(2, 3);
+    /// let mut map: GridMap<char> = GridMap::from(grid);
+    /// map.insert(cell, '#');
+    ///
+    /// assert!(map.occupied(cell));
+    /// assert!(!map.occupied(map.grid().start()));
+    /// ```
+    pub fn occupied(&self, cell: Cell) -> bool {
+        cell.within_panic(self.grid);
+        self.contains_key(&cell)
+    }
+
+    /// Checks if the `Cell` is free
+    ///
+    /// # Panics
+    /// Panics, if the given `Cell` is not within the inner `Grid`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid, GridMap};
+    ///
+    /// let grid = Grid::new(5, 5);
+    /// let cell = Cell::new(2, 3);
+    /// let mut map: GridMap<char> = GridMap::from(grid);
+    /// map.insert(cell, '#');
+    ///
+    /// assert!(!map.vacant(cell));
+    /// assert!(map.vacant(map.grid().start()));
+    /// ```
+    pub fn vacant(&self, cell: Cell) -> bool {
+        cell.within_panic(self.grid);
+        !self.contains_key(&cell)
+    }
+
+    /// Returns count of occupied `Cell`s
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid, GridMap};
+    ///
+    /// let mut map: GridMap<char> = GridMap::new(5, 5);
+    /// map.insert(Cell::new(2, 3), '#');
+    /// map.insert(Cell::new(4, 1), '@');
+    ///
+    /// assert_eq!(map.occupied_count(), 2);
+    /// ```
+    pub fn occupied_count(&self) -> u16 {
+        self.len() as u16
+    }
+
+    /// Returns count of vacant `Cell`s
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid, GridMap};
+    ///
+    /// let mut map: GridMap<char> = GridMap::new(5, 5);
+    /// map.insert(Cell::new(2, 3), '#');
+    /// map.insert(Cell::new(4, 1), '@');
+    ///
+    /// assert_eq!(map.vacant_count(), 23);
+    /// ```
+    pub fn vacant_count(&self) -> u16 {
+        self.grid.size() - self.occupied_count()
+    }
+
+    /// Returns an iterator over every occupied `Cell`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid, GridMap};
+    ///
+    /// let mut map: GridMap<char> = GridMap::new(5, 5);
+    /// map.insert(Cell::new(2, 3), '#');
+    /// map.insert(Cell::new(4, 1), '@');
+    ///
+    /// assert_eq!(map.all_occupied().count(), 2);
+    /// ```
+    pub fn all_occupied(&self) -> Filter<Cells, impl FnMut(&Cell) -> bool> {
+        self.grid.cells().filter(|&cell| self.occupied(cell))
+    }
+
+    /// Returns an iterator over every vacant `Cell`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid, GridMap};
+    ///
+    /// let mut map: GridMap<char> = GridMap::new(5, 5);
+    /// map.insert(Cell::new(2, 3), '#');
+    /// map.insert(Cell::new(4, 1), '@');
+    ///
+    /// assert_eq!(map.all_vacant().count(), 23);
+    /// ```
+    pub fn all_vacant(&self) -> Filter<Cells, impl FnMut(&Cell) -> bool> {
+        self.grid.cells().filter(|&cell| self.vacant(cell))
+    }
+
+    /// Returns first occupied `Cell`
+    ///
+    /// # Note
+    /// This returns first `Cell` in `Grid` order, so (2, 3) will go after (4, 1)
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid, GridMap};
+    ///
+    /// let mut map: GridMap<char> = GridMap::new(5, 5);
+    /// map.insert(Cell::new(2, 3), '#');
+    /// map.insert(Cell::new(4, 1), '@');
+    ///
+    /// assert_eq!(map.first_occupied(), Some(Cell::new(4, 1)));
+    /// ```
+    pub fn first_occupied(&self) -> Option<Cell> {
+        self.all_occupied().next()
+    }
+
+    /// Returns first vacant `Cell`
+    ///
+    /// # Note
+    /// This returns first `Cell` in `Grid` order
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid, GridMap};
+    ///
+    /// let mut map: GridMap<char> = GridMap::new(5, 5);
+    /// map.insert(Cell::new(2, 3), '#');
+    /// map.insert(Cell::new(4, 1), '@');
+    ///
+    /// assert_eq!(map.first_vacant(), Some(Cell::new(0, 0)));
+    /// ```
+    pub fn first_vacant(&self) -> Option<Cell> {
+        self.all_vacant().next()
+    }
+
+    /// Returns random occupied `Cell`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid, GridMap};
+    ///
+    /// let mut map: GridMap<char> = GridMap::new(5, 5);
+    /// map.insert(Cell::new(2, 3), '#');
+    /// map.insert(Cell::new(4, 1), '@');
+    ///
+    /// assert_ne!(map.get(&map.random_occupied().unwrap()), None);
+    /// assert_ne!(map.get(&map.random_occupied().unwrap()), None);
+    /// assert_ne!(map.get(&map.random_occupied().unwrap()), None);
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random_occupied(&self) -> Option<Cell> {
+        self.all_occupied().choose(&mut rand::rng())
+    }
+
+    /// Returns random vacant `Cell`
     ///
-    /// # Examples
+    /// # Examples:
     ///
     /// ```
-    /// use grid_math::{Cell, Grid};
+    /// use grid_math::{Cell, Grid, GridMap};
     ///
-    /// let grid = Grid::new(5, 5);
-    /// let cells: (Cell, Cell) = grid.into();
-    /// assert_eq!((cells.0, cells.1), (grid.start(), grid.end()));
+    /// let mut map: GridMap<char> = GridMap::new(5, 5);
+    /// map.insert(Cell::new(2, 3), '#');
+    /// map.insert(Cell::new(4, 1), '@');
+    ///
+    /// assert_eq!(map.get(&map.random_vacant().unwrap()), None);
+    /// assert_eq!(map.get(&map.random_vacant().unwrap()), None);
+    /// assert_eq!(map.get(&map.random_vacant().unwrap()), None);
     /// ```
-    fn into(self) -> (Cell, Cell) {
-        (self.start, self.end)
+    #[cfg(feature = "rand")]
+    pub fn random_vacant(&self) -> Option<Cell> {
+        self.all_vacant().choose(&mut rand::rng())
     }
-}
 
-impl From<((u8, u8), (u8, u8))> for Grid {
-    /// implements constructor for `Grid` from ((u8, u8), (u8, u8))
+    /// Compares `GridMap` with another one, and returns every changed `Cell` alongside with `CellDiff`
     ///
-    /// # Examples
+    /// # Note
+    /// Changes are returned in `Grid` order, unchanged `Cell`s are skipped
+    ///
+    /// # Panics
+    /// Panics, if the inner `Grid`s of the maps are not the same
+    ///
+    /// # Examples:
     ///
     /// ```
-    /// use grid_math::{Cell, Grid};
+    /// use grid_math::{Cell, CellDiff, GridMap};
     ///
-    /// let vals = ((2, 2), (5, 5));
-    /// let grid = Grid::from(vals);
-    /// assert_eq!((Cell::from(vals.0), Cell::from(vals.1)), (grid.start(), grid.end()));
+    /// let mut before: GridMap<char> = GridMap::new(5, 5);
+    /// before.insert(Cell::new(1, 0), '#');
+    /// before.insert(Cell::new(2, 0), '#');
+    /// before.insert(Cell::new(3, 0), '#');
+    ///
+    /// let mut after = before.clone();
+    /// after.insert(Cell::new(0, 0), '@'); // added
+    /// after.remove(&Cell::new(1, 0)); // removed
+    /// after.insert(Cell::new(2, 0), '$'); // changed
+    /// after.insert(Cell::new(3, 0), '#'); // unchanged
+    ///
+    /// assert_eq!(before.diff(&after), vec![
+    ///     (Cell::new(0, 0), CellDiff::Added(&'@')),
+    ///     (Cell::new(1, 0), CellDiff::Removed(&'#')),
+    ///     (Cell::new(2, 0), CellDiff::Changed(&'#', &'$')),
+    /// ]);
+    /// assert!(before.diff(&before).is_empty());
     /// ```
-    fn from(value: ((u8, u8), (u8, u8))) -> Self {
-        let (start, end): (Cell, Cell) = (value.0.into(), value.1.into());
-        if start.global_width > end.global_width || start.global_depth > end.global_depth {
-            panic!("start cell overflows end cell! start:{start}, end:{end}")
+    ///
+    /// ```should_panic
+    /// use grid_math::GridMap;
+    ///
+    /// let first: GridMap<char> = GridMap::new(5, 5);
+    /// let second: GridMap<char> = GridMap::new(4, 4);
+    /// first.diff(&second); // panic!
+    /// ```
+    pub fn diff<'a>(&'a self, other: &'a GridMap<V>) -> Vec<(Cell, CellDiff<&'a V>)>
+    where
+        V: PartialEq,
+    {
+        if self.grid != other.grid {
+            panic!(
+                "maps have different grids! grid:{grid}, other:{other}",
+                grid = self.grid,
+                other = other.grid
+            )
         }
-        Self { start, end }
+        self.grid
+            .cells()
+            .filter_map(|cell| match (self.get(&cell), other.get(&cell)) {
+                (None, Some(new)) => Some((cell, CellDiff::Added(new))),
+                (Some(old), None) => Some((cell, CellDiff::Removed(old))),
+                (Some(old), Some(new)) if old != new => Some((cell, CellDiff::Changed(old, new))),
+                _ => None,
+            })
+            .collect()
     }
-}
 
-#[allow(clippy::from_over_into)]
-impl Into<((u8, u8), (u8, u8))> for Grid {
-    /// implements conversion from `Grid` into ((u8, u8), (u8, u8))
+    /// Returns count of occupied `Cell`s for every row of the inner `Grid`,
+    /// where index of the count is the relative `depth` of the row
     ///
-    /// # Examples
+    /// # Examples:
     ///
     /// ```
-    /// use grid_math::{Cell, Grid};
+    /// use grid_math::{Cell, GridMap};
     ///
-    /// let grid = Grid::new(5, 5);
-    /// let vals: ((u8, u8), (u8, u8)) = grid.into();
-    /// assert_eq!((Cell::from(vals.0), Cell::from(vals.1)), (grid.start(), grid.end()));
+    /// let mut map: GridMap<char> = GridMap::new(4, 3);
+    /// map.insert(Cell::new(0, 0), '#');
+    /// map.insert(Cell::new(1, 0), '#');
+    /// map.insert(Cell::new(3, 0), '#');
+    /// map.insert(Cell::new(2, 2), '@');
+    ///
+    /// assert_eq!(map.counts_per_row(), vec![3, 0, 1]);
     /// ```
-    fn into(self) -> ((u8, u8), (u8, u8)) {
-        (self.start.into(), self.end.into())
+    pub fn counts_per_row(&self) -> Vec<u16> {
+        let mut counts = vec![0; self.grid.depth() as usize];
+        self.keys()
+            .for_each(|cell| counts[cell.depth(self.grid) as usize] += 1);
+        counts
     }
-}
 
-impl fmt::Display for Grid {
-    /// implements display for `Grid`
+    /// Returns count of occupied `Cell`s for every column of the inner `Grid`,
+    /// where index of the count is the relative `width` of the column
     ///
-    /// # Examples
+    /// # Examples:
     ///
     /// ```
-    /// use grid_math::Grid;
+    /// use grid_math::{Cell, GridMap};
     ///
-    /// let grid = Grid::new(5, 6);
-    /// assert_eq!(format!("{grid}"), "[(0, 0):(4, 5)]");
+    /// let mut map: GridMap<char> = GridMap::new(4, 3);
+    /// map.insert(Cell::new(0, 0), '#');
+    /// map.insert(Cell::new(1, 0), '#');
+    /// map.insert(Cell::new(3, 0), '#');
+    /// map.insert(Cell::new(3, 2), '@');
+    ///
+    /// assert_eq!(map.counts_per_column(), vec![1, 1, 0, 2]);
     /// ```
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "[{start}:{end}]", start = self.start, end = self.end)
+    pub fn counts_per_column(&self) -> Vec<u16> {
+        let mut counts = vec![0; self.grid.width() as usize];
+        self.keys()
+            .for_each(|cell| counts[cell.width(self.grid) as usize] += 1);
+        counts
     }
-}
 
-impl From<Grid> for Cells {
-    /// Creates new iterator over every `Cell` on the `Grid`
+    /// Inserts new object into the nearest to the given `Cell` member of the inner `Grid`
+    ///
+    /// Unlike `insert`, this method does not panic if the `Cell` is not within the inner `Grid`,
+    /// instead it clamps the `Cell` into the `Grid` bounds with `Grid::clamp`.
+    /// Returns the displaced value, if there was one
     ///
     /// # Examples:
     ///
     /// ```
-    /// use grid_math::{Grid, Cells};
+    /// use grid_math::{Cell, GridMap};
     ///
-    /// let grid = Grid::new(5, 5);
-    /// let cells = Cells::from(grid);
+    /// let mut map: GridMap<char> = GridMap::new(5, 5);
+    /// assert_eq!(map.saturating_insert(Cell::new(9, 7), '#'), None);
+    /// assert_eq!(map.get(&Cell::new(4, 4)), Some(&'#'));
+    ///
+    /// assert_eq!(map.saturating_insert(Cell::new(4, 200), '@'), Some('#'));
+    /// assert_eq!(map.get(&Cell::new(4, 4)), Some(&'@'));
     /// ```
-    fn from(grid: Grid) -> Self {
-        Self {
-            grid,
-            current: grid.start,
-            consumed: false,
-        }
+    pub fn saturating_insert(&mut self, cell: Cell, value: V) -> Option<V> {
+        self.hashmap.insert(self.grid.clamp(cell), value)
     }
-}
 
-impl From<Grid> for Columns {
-    /// Creates new iterator over every column on the `Grid`
+    /// Returns new `GridMap`, rotated by 90 degrees clockwise
+    ///
+    /// The inner `Grid` of the new `GridMap` is `grid.rotate_90_cw()`,
+    /// and every entry is moved with `Cell::rotate_90_cw`
+    ///
+    /// # Panics
+    /// Panics if the rotated `Grid` overflows `u8` global position bounds
     ///
     /// # Examples:
     ///
     /// ```
-    /// use grid_math::{Grid, Columns};
+    /// use grid_math::{Cell, Grid, GridMap};
     ///
-    /// let grid = Grid::new(5, 5);
-    /// let columns = Columns::from(grid);
-    /// ```
-    fn from(grid: Grid) -> Self {
-        Self {
-            grid,
-            current: Grid {
-                start: grid.start,
-                end: grid.start.project_down(grid),
-            },
-            consumed: false,
+    /// let mut map: GridMap<char> = GridMap::new(3, 2);
+    /// map.insert(Cell::new(0, 0), 'a');
+    /// map.insert(Cell::new(2, 0), 'b');
+    /// map.insert(Cell::new(1, 1), 'c');
+    ///
+    /// let rotated = map.rotated_90_cw();
+    /// assert_eq!(rotated.grid(), Grid::new(2, 3));
+    /// assert_eq!(rotated.get(&Cell::new(1, 0)), Some(&'a'));
+    /// assert_eq!(rotated.get(&Cell::new(1, 2)), Some(&'b'));
+    /// assert_eq!(rotated.get(&Cell::new(0, 1)), Some(&'c'));
+    ///
+    /// let restored = rotated.rotated_90_cw().rotated_90_cw().rotated_90_cw();
+    /// assert!(map.diff(&restored).is_empty());
+    /// ```
+    pub fn rotated_90_cw(&self) -> GridMap<V>
+    where
+        V: Clone,
+    {
+        GridMap {
+            grid: self.grid.rotate_90_cw(),
+            hashmap: self
+                .hashmap
+                .iter()
+                .map(|(cell, value)| (cell.rotate_90_cw(self.grid), value.clone()))
+                .collect(),
         }
     }
-}
 
-impl From<Grid> for Rows {
-    /// Creates new iterator over every row on the `Grid`
+    /// Checks if every `Cell` of the given region is occupied
+    ///
+    /// Stops at the first vacant `Cell`
+    ///
+    /// # Panics
+    /// Panics, if the given region is not within the inner `Grid`
     ///
     /// # Examples:
     ///
     /// ```
-    /// use grid_math::{Grid, Rows};
+    /// use grid_math::{Cell, Grid, GridMap};
     ///
-    /// let grid = Grid::new(5, 5);
-    /// let rows = Rows::from(grid);
+    /// let mut map: GridMap<char> = GridMap::new(5, 5);
+    /// let region = Grid::indented(2, 2, (1, 1));
+    /// region.cells().for_each(|cell| {
+    ///     map.insert(cell, '#');
+    /// });
+    /// assert!(map.region_fully_occupied(region));
+    ///
+    /// map.remove(&Cell::new(2, 2));
+    /// assert!(!map.region_fully_occupied(region));
     /// ```
-    fn from(grid: Grid) -> Self {
-        Self {
-            grid,
-            current: Grid {
-                start: grid.start,
-                end: grid.start.project_right(grid),
-            },
-            consumed: false,
-        }
-    }
-}
-
-impl Iterator for Cells {
-    type Item = Cell;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.consumed {
-            return None;
-        }
-        if self.current == self.grid.end {
-            self.consumed = true;
-            return Some(self.current);
-        }
-        let previous = self.current;
-        match self.current.overflowing_right(self.grid, 1) {
-            (next, true) => self.current = next.wrapping_down(self.grid, 1),
-            (next, false) => self.current = next,
-        }
-        Some(previous)
+    ///
+    /// ```should_panic
+    /// use grid_math::{Grid, GridMap};
+    ///
+    /// let map: GridMap<char> = GridMap::new(5, 5);
+    /// map.region_fully_occupied(Grid::new(6, 6)); // panic!
+    /// ```
+    pub fn region_fully_occupied(&self, region: Grid) -> bool {
+        region.within_panic(self.grid);
+        region.cells().all(|cell| self.contains_key(&cell))
     }
-}
 
-impl Iterator for Columns {
-    type Item = Grid;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.consumed {
-            return None;
-        }
-        if self.current.end == self.grid.end {
-            self.consumed = true;
-            return Some(self.current);
-        }
-        let previous = self.current;
-        self.current = Grid {
-            start: self.current.start.saturating_right(self.grid, 1),
-            end: self.current.end.saturating_right(self.grid, 1),
+    /// Returns every occupied `Cell` alongside with its value,
+    /// that is within `radius` from the `center` by the given `DistanceMetric`
+    ///
+    /// # Note
+    /// Results are sorted by distance from the `center`, cells with equal distance go in `Grid` order
+    ///
+    /// # Panics
+    /// Panics, if the `center` is not within the inner `Grid`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Cell, DistanceMetric, GridMap};
+    ///
+    /// let mut map: GridMap<char> = GridMap::new(5, 5);
+    /// map.insert(Cell::new(0, 0), 'a');
+    /// map.insert(Cell::new(1, 2), 'b');
+    /// map.insert(Cell::new(1, 1), 'c');
+    /// map.insert(Cell::new(4, 4), 'd');
+    ///
+    /// let near = map.values_within(Cell::new(0, 1), 2, DistanceMetric::Manhattan);
+    /// assert_eq!(near, vec![
+    ///     (Cell::new(0, 0), &'a'),
+    ///     (Cell::new(1, 1), &'c'),
+    ///     (Cell::new(1, 2), &'b'),
+    /// ]);
+    ///
+    /// let near = map.values_within(Cell::new(4, 4), 3, DistanceMetric::Chebyshev);
+    /// assert_eq!(near, vec![
+    ///     (Cell::new(4, 4), &'d'),
+    ///     (Cell::new(1, 1), &'c'),
+    ///     (Cell::new(1, 2), &'b'),
+    /// ]);
+    /// ```
+    pub fn values_within(
+        &self,
+        center: Cell,
+        radius: u8,
+        metric: DistanceMetric,
+    ) -> Vec<(Cell, &V)> {
+        center.within_panic(self.grid);
+        let region = Grid {
+            start: self.grid.clamp(Cell {
+                global_width: center.global_width.saturating_sub(radius),
+                global_depth: center.global_depth.saturating_sub(radius),
+            }),
+            end: self.grid.clamp(Cell {
+                global_width: center.global_width.saturating_add(radius),
+                global_depth: center.global_depth.saturating_add(radius),
+            }),
         };
-        Some(previous)
+        let mut values: Vec<(Cell, &V)> = region
+            .cells()
+            .filter(|&cell| cell.distance(center, metric) <= radius as u16)
+            .filter_map(|cell| self.get(&cell).map(|value| (cell, value)))
+            .collect();
+        values.sort_by_key(|&(cell, _)| cell.distance(center, metric));
+        values
     }
-}
 
-impl Iterator for Rows {
-    type Item = Grid;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.consumed {
-            return None;
-        }
-        if self.current.end == self.grid.end {
-            self.consumed = true;
-            return Some(self.current);
-        }
-        let previous = self.current;
-        self.current = Grid {
-            start: self.current.start.saturating_down(self.grid, 1),
-            end: self.current.end.saturating_down(self.grid, 1),
-        };
-        Some(previous)
+    /// Parses `GridMap` from the lines of text, inferring `Grid` dimensions from them
+    ///
+    /// Each `char` is passed to `parse`: the `Cell` is occupied by the returned value if it is `Some`,
+    /// and left vacant otherwise
+    ///
+    /// # Errors
+    /// Returns `ParseError::DimensionOverflow`, if the number of lines or their length does not fit into `u8`
+    /// Returns `ParseError::RaggedOrInconsistent`, if there are no lines, any line is empty, or lines differ in length
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Cell, GridMap, ParseError};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Tile {
+    ///     Wall,
+    ///     Door,
+    /// }
+    ///
+    /// let parse = |value| match value {
+    ///     '#' => Some(Tile::Wall),
+    ///     '+' => Some(Tile::Door),
+    ///     _ => None,
+    /// };
+    ///
+    /// let map = GridMap::from_lines(&["####", "#  +", "####"], parse).unwrap();
+    /// assert_eq!((map.grid().width(), map.grid().depth()), (4, 3));
+    /// assert_eq!(map.len(), 10);
+    /// assert_eq!(map.get(&Cell::new(3, 1)), Some(&Tile::Door));
+    /// assert_eq!(map.get(&Cell::new(1, 1)), None);
+    ///
+    /// let wide = "#".repeat(300);
+    /// assert_eq!(GridMap::from_lines(&[&wide], parse).unwrap_err(), ParseError::DimensionOverflow);
+    /// assert_eq!(GridMap::from_lines(&["##", "#"], parse).unwrap_err(), ParseError::RaggedOrInconsistent);
+    /// ```
+    pub fn from_lines(
+        lines: &[&str],
+        parse: impl Fn(char) -> Option<V>,
+    ) -> Result<GridMap<V>, ParseError> {
+        GridMap::parse_lines(lines, |value| Ok(parse(value)))
     }
-}
 
-impl<V> From<Grid> for GridMap<V> {
-    /// Creates new `GridMap` from the given `Grid` with empty `HashMap<Cell, V>`
+    /// Parses `GridMap` from the lines of text, inferring `Grid` dimensions from them,
+    /// and rejecting every `char` that is not recognized
+    ///
+    /// This is a strict counterpart of the `from_lines` method:
+    /// `Cell`s with `empty` are left vacant, and every other `char` must be recognized by `parse`
+    ///
+    /// # Errors
+    /// Returns `ParseError::DimensionOverflow`, if the number of lines or their length does not fit into `u8`
+    /// Returns `ParseError::RaggedOrInconsistent`, if there are no lines, any line is empty, or lines differ in length
+    /// Returns `ParseError::UnknownChar`, if `parse` returns `None` for any `char` other than `empty`
     ///
     /// # Examples:
     ///
     /// ```
-    /// use grid_math::{Grid, GridMap};
+    /// use grid_math::{Cell, GridMap, ParseError};
     ///
-    /// let grid = Grid::new(5, 5);
-    /// let map: GridMap<char> = GridMap::from(grid);
+    /// let parse = |value| match value {
+    ///     '#' => Some(true),
+    ///     '+' => Some(false),
+    ///     _ => None,
+    /// };
+    ///
+    /// let map = GridMap::from_lines_strict(&["###", "#.+"], parse, '.').unwrap();
+    /// assert_eq!(map.len(), 5);
+    /// assert_eq!(map.get(&Cell::new(2, 1)), Some(&false));
+    /// assert_eq!(map.get(&Cell::new(1, 1)), None);
+    ///
+    /// assert_eq!(
+    ///     GridMap::from_lines_strict(&["###", "#?+"], parse, '.').unwrap_err(),
+    ///     ParseError::UnknownChar('?')
+    /// );
     /// ```
-    fn from(grid: Grid) -> Self {
-        Self {
-            grid,
-            hashmap: HashMap::new(),
+    pub fn from_lines_strict(
+        lines: &[&str],
+        parse: impl Fn(char) -> Option<V>,
+        empty: char,
+    ) -> Result<GridMap<V>, ParseError> {
+        GridMap::parse_lines(lines, |value| {
+            if value == empty {
+                return Ok(None);
+            }
+            parse(value).map(Some).ok_or(ParseError::UnknownChar(value))
+        })
+    }
+
+    /// Parses `GridMap` from the lines of text with the fallible `parse`, shared by `from_lines` methods
+    fn parse_lines(
+        lines: &[&str],
+        parse: impl Fn(char) -> Result<Option<V>, ParseError>,
+    ) -> Result<GridMap<V>, ParseError> {
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        if width == 0 || lines.iter().any(|line| line.chars().count() != width) {
+            return Err(ParseError::RaggedOrInconsistent);
         }
+        let width = u8::try_from(width).map_err(|_| ParseError::DimensionOverflow)?;
+        let depth = u8::try_from(lines.len()).map_err(|_| ParseError::DimensionOverflow)?;
+        let mut map = GridMap::new(width, depth);
+        for (cells, line) in map.grid.rows().map(Grid::cells).zip(lines) {
+            for (cell, value) in cells.zip(line.chars()) {
+                if let Some(value) = parse(value)? {
+                    map.hashmap.insert(cell, value);
+                }
+            }
+        }
+        Ok(map)
     }
-}
 
-impl<V> From<(Grid, HashMap<Cell, V>)> for GridMap<V> {
-    /// Creates new `GridMap` from the existing `HashMap<Cell, V>` and the given `Grid`
+    /// Returns mutable ref to the value of the given `Cell`,
+    /// inserting the value computed by `f` first, if the `Cell` is vacant
+    ///
+    /// `f` is called only if the `Cell` is vacant
     ///
     /// # Panics
-    /// Panics if the given `HashMap<Cell, V>` contains `Cell`s that are not within the given `Grid`
+    /// Panics, if the key (`Cell`) is not within the inner `Grid`
     ///
     /// # Examples:
     ///
     /// ```
-    /// use grid_math::{Cell, Grid, GridMap};
-    /// use std::collections::HashMap;
+    /// use grid_math::{Cell, GridMap};
     ///
-    /// let grid = Grid::new(5, 5);
-    /// let mut hashmap: HashMap<Cell, char> = HashMap::new();
-    /// let target = Cell::new(1, 2);
-    /// hashmap.insert(target, '#');
-    /// let map: GridMap<char> = GridMap::from((grid, hashmap));
-    /// assert_eq!(map.get(&target), Some(&'#'));
+    /// let mut map: GridMap<u32> = GridMap::new(5, 5);
+    /// let cell = Cell::new(2, 3);
+    /// *map.get_or_insert_with(cell, || 10) += 1;
+    /// assert_eq!(map.get(&cell), Some(&11));
+    ///
+    /// let mut called = false;
+    /// *map.get_or_insert_with(cell, || {
+    ///     called = true;
+    ///     0
+    /// }) += 1;
+    /// assert!(!called);
+    /// assert_eq!(map.get(&cell), Some(&12));
     /// ```
     ///
     /// ```should_panic
-    /// use grid_math::{Cell, Grid, GridMap};
-    /// use std::collections::HashMap;
-    ///
-    /// let grid = Grid::new(5, 5);
-    /// let mut hashmap: HashMap<Cell, char> = HashMap::new();
-    /// let target = Cell::new(6, 2);
-    /// hashmap.insert(target, '#');
-    /// let map: GridMap<char> = GridMap::from((grid, hashmap)); // panic!
-    /// ```
-    fn from(data: (Grid, HashMap<Cell, V>)) -> Self {
-        data.1.keys().for_each(|cell| cell.within_panic(data.0));
-        Self {
-            grid: data.0,
-            hashmap: data.1,
-        }
+    /// use grid_math::{Cell, GridMap};
+    ///
+    /// let mut map: GridMap<u32> = GridMap::new(5, 5);
+    /// map.get_or_insert_with(Cell::new(5, 0), || 0); // panic!
+    /// ```
+    pub fn get_or_insert_with(&mut self, cell: Cell, f: impl FnOnce() -> V) -> &mut V {
+        cell.within_panic(self.grid);
+        self.hashmap.entry(cell).or_insert_with(f)
     }
-}
 
-impl<V> GridMap<V> {
-    /// Creates new `GridMap` with `Grid` of specified sizes, and with empty `HashMap<Cell, V>`
+    /// Retains only entries, whose values satisfy the predicate, removing all the others
     ///
     /// # Examples:
     ///
     /// ```
-    /// use grid_math::{Grid, GridMap};
+    /// use grid_math::{Cell, GridMap};
     ///
-    /// let map: GridMap<char> = GridMap::new(5, 5);
+    /// let mut map: GridMap<i32> = GridMap::new(5, 5);
+    /// map.insert(Cell::new(0, 0), 10);
+    /// map.insert(Cell::new(1, 2), -3);
+    /// map.insert(Cell::new(4, 4), 0);
+    /// map.insert(Cell::new(3, 1), 7);
     ///
-    /// assert_eq!(map.grid(), Grid::new(5, 5));
+    /// map.retain_values(|&health| health > 0);
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map.get(&Cell::new(0, 0)), Some(&10));
+    /// assert_eq!(map.get(&Cell::new(3, 1)), Some(&7));
     /// ```
-    pub fn new(width: u8, depth: u8) -> Self {
-        Self {
-            grid: Grid::new(width, depth),
-            hashmap: HashMap::new(),
-        }
+    pub fn retain_values(&mut self, pred: impl Fn(&V) -> bool) {
+        self.hashmap.retain(|_, value| pred(value));
     }
 
-    /// Shadows `insert` method from the `HashMap`, and reimplements it
-    /// so it checks first if the key (`Cell`) is within the `Grid`, and then inserts it into the `HashMap`.
-    /// This method currently has bad error handling, but this may change in the future
-    ///
-    /// # Panics
-    /// Panics, if the key (`Cell`) is not within the inner `Grid`
+    /// Inserts clone of the `value` into every `Cell` of the inner `Grid`, replacing existing values
     ///
     /// # Examples:
     ///
     /// ```
-    /// use grid_math::{Grid, GridMap};
+    /// use grid_math::{Cell, GridMap};
     ///
-    /// let grid = Grid::new(5, 5);
-    /// let mut map: GridMap<char> = GridMap::from(grid);
-    /// map.insert(map.grid().start(), '#');
-    /// map.insert(map.grid().end(), '@');
-    /// assert_eq!(map.len(), 2);
+    /// let mut map: GridMap<char> = GridMap::new(4, 3);
+    /// map.insert(Cell::new(1, 1), '@');
+    /// map.fill('.');
+    /// assert_eq!(map.len(), map.grid().size() as usize);
+    /// assert_eq!(map.get(&Cell::new(1, 1)), Some(&'.'));
     /// ```
+    pub fn fill(&mut self, value: V)
+    where
+        V: Clone,
+    {
+        self.fill_with(|_| value.clone());
+    }
+
+    /// Inserts value computed by `f` into every `Cell` of the inner `Grid`, replacing existing values
     ///
-    /// ```should_panic
-    /// use grid_math::{Cell, Grid, GridMap};
+    /// `f` receives every `Cell` exactly once, in `Grid` order
+    ///
+    /// # Examples:
     ///
-    /// let grid = Grid::new(5, 5);
-    /// let cell = Cell::new(6, 6);
-    /// let mut map: GridMap<char> = GridMap::from(grid);
-    /// map.insert(cell, '#'); // panic!
     /// ```
-    pub fn insert(&mut self, cell: Cell, value: V) -> Option<V> {
-        cell.within_panic(self.grid);
-        self.hashmap.insert(cell, value)
+    /// use grid_math::{Cell, GridMap};
+    ///
+    /// let mut map: GridMap<char> = GridMap::new(3, 2);
+    /// let mut visited = Vec::new();
+    /// map.fill_with(|cell| {
+    ///     visited.push(cell);
+    ///     if (cell.global_width() + cell.global_depth()) % 2 == 0 { '#' } else { '.' }
+    /// });
+    /// assert_eq!(visited, map.grid().cells().collect::<Vec<_>>());
+    /// assert_eq!(map.get(&Cell::new(0, 0)), Some(&'#'));
+    /// assert_eq!(map.get(&Cell::new(1, 0)), Some(&'.'));
+    /// assert_eq!(map.get(&Cell::new(1, 1)), Some(&'#'));
+    /// ```
+    pub fn fill_with(&mut self, mut f: impl FnMut(Cell) -> V) {
+        for cell in self.grid.cells() {
+            self.hashmap.insert(cell, f(cell));
+        }
     }
 
-    /// Inserts new object only if the `Cell` is not occupied.
-    /// Returns `true` if inserted, and `false` if not
+    /// Returns neighbors of the given `Cell`, that are within the inner `Grid`,
+    /// together with the `Direction` to them and their values
+    ///
+    /// Neighbors go in the same order as in `Cell::neighbors`, or `Cell::neighbors_diagonal` if `diagonal` is `true`
     ///
     /// # Panics
-    /// Panics, if the key (`Cell`) is not within the inner `Grid`
+    /// Panics, if the `Cell` is not within the inner `Grid`
     ///
     /// # Examples:
     ///
     /// ```
-    /// use grid_math::{Grid, GridMap};
+    /// use grid_math::{Cell, GridMap, Heading};
     ///
-    /// let grid = Grid::new(5, 5);
-    /// let mut map: GridMap<char> = GridMap::from(grid);
-    /// assert!(map.vacant_insert(map.grid().start(), '#'));
-    /// assert!(!map.vacant_insert(map.grid().start(), '@'));
-    /// assert_eq!(map.get(&map.grid().start()), Some(&'#'));
-    /// ```
+    /// let mut map: GridMap<char> = GridMap::new(5, 5);
+    /// map.insert(Cell::new(2, 1), '#');
+    /// map.insert(Cell::new(3, 3), '@');
+    ///
+    /// let entries = map.neighbor_entries(Cell::new(2, 2), false);
+    /// assert_eq!(entries, vec![
+    ///     (Heading::Up, Cell::new(2, 1), Some(&'#')),
+    ///     (Heading::Down, Cell::new(2, 3), None),
+    ///     (Heading::Left, Cell::new(1, 2), None),
+    ///     (Heading::Right, Cell::new(3, 2), None),
+    /// ]);
     ///
-    /// ```should_panic
-    /// use grid_math::{Cell, Grid, GridMap};
+    /// let entries = map.neighbor_entries(Cell::new(2, 2), true);
+    /// assert_eq!(entries.len(), 8);
+    /// assert_eq!(entries[7], (Heading::DownRight, Cell::new(3, 3), Some(&'@')));
     ///
-    /// let grid = Grid::new(5, 5);
-    /// let cell = Cell::new(6, 6);
-    /// let mut map: GridMap<char> = GridMap::from(grid);
-    /// map.vacant_insert(cell, '#'); // panic!
+    /// let entries = map.neighbor_entries(Cell::new(0, 0), true);
+    /// assert_eq!(entries, vec![
+    ///     (Heading::Down, Cell::new(0, 1), None),
+    ///     (Heading::Right, Cell::new(1, 0), None),
+    ///     (Heading::DownRight, Cell::new(1, 1), None),
+    /// ]);
     /// ```
-    pub fn vacant_insert(&mut self, cell: Cell, value: V) -> bool {
+    pub fn neighbor_entries(&self, cell: Cell, diagonal: bool) -> Vec<(Heading, Cell, Option<&V>)> {
         cell.within_panic(self.grid);
-        if self.vacant(cell) {
-            self.hashmap.insert(cell, value);
-            true
+        let headings = if diagonal {
+            &Heading::ALL[..]
         } else {
-            false
+            &Heading::ALL[..4]
+        };
+        headings
+            .iter()
+            .filter_map(|&heading| {
+                let neighbor = cell.checked_offset(self.grid, heading.offset())?;
+                Some((heading, neighbor, self.get(&neighbor)))
+            })
+            .collect()
+    }
+
+    /// Rebinds `GridMap` to the new `Grid`, keeping every entry, that is still within the new `Grid`.
+    /// Returns the new `GridMap` and entries, that do not fit into the new `Grid`, in the old `Grid` order
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid, GridMap};
+    ///
+    /// let mut map: GridMap<char> = GridMap::new(5, 5);
+    /// map.insert(Cell::new(1, 1), '#');
+    /// map.insert(Cell::new(4, 0), '@');
+    /// map.insert(Cell::new(3, 4), '%');
+    ///
+    /// let (expanded, dropped) = map.clone().rebind(Grid::new(8, 8));
+    /// assert_eq!(expanded.len(), 3);
+    /// assert!(dropped.is_empty());
+    ///
+    /// let (shrunk, dropped) = map.rebind(Grid::new(3, 3));
+    /// assert_eq!(shrunk.grid(), Grid::new(3, 3));
+    /// assert_eq!(shrunk.len(), 1);
+    /// assert_eq!(shrunk.get(&Cell::new(1, 1)), Some(&'#'));
+    /// assert_eq!(dropped, vec![(Cell::new(4, 0), '@'), (Cell::new(3, 4), '%')]);
+    /// ```
+    pub fn rebind(mut self, new_grid: Grid) -> (GridMap<V>, Vec<(Cell, V)>) {
+        let mut map = GridMap::from(new_grid);
+        let mut dropped = Vec::new();
+        for cell in self.grid.cells() {
+            if let Some(value) = self.hashmap.remove(&cell) {
+                if cell.within(new_grid) {
+                    map.hashmap.insert(cell, value);
+                } else {
+                    dropped.push((cell, value));
+                }
+            }
         }
+        (map, dropped)
     }
 
-    /// Returns the inner `Grid`
+    /// Replaces the inner `Grid` with the new one in place, removing every entry, that is not within the new `Grid`.
+    /// Returns the number of removed entries
     ///
     /// # Examples:
     ///
     /// ```
-    /// use grid_math::{Grid, GridMap};
+    /// use grid_math::{Cell, Grid, GridMap};
     ///
-    /// let grid = Grid::new(5, 5);
-    /// let map: GridMap<char> = GridMap::from(grid);
+    /// let mut map: GridMap<char> = GridMap::new(5, 5);
+    /// map.insert(Cell::new(1, 1), '#');
+    /// map.insert(Cell::new(2, 2), '$');
+    /// map.insert(Cell::new(4, 0), '@');
+    /// map.insert(Cell::new(3, 4), '%');
     ///
-    /// assert_eq!(grid, map.grid());
+    /// assert_eq!(map.resize(Grid::new(3, 3)), 2);
+    /// assert_eq!(map.grid(), Grid::new(3, 3));
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map.get(&Cell::new(1, 1)), Some(&'#'));
+    /// assert_eq!(map.get(&Cell::new(2, 2)), Some(&'$'));
+    ///
+    /// assert_eq!(map.resize(Grid::new(8, 8)), 0);
+    /// assert_eq!(map.len(), 2);
     /// ```
-    pub fn grid(&self) -> Grid {
-        self.grid
+    pub fn resize(&mut self, new_grid: Grid) -> usize {
+        let before = self.hashmap.len();
+        self.grid = new_grid;
+        self.hashmap.retain(|cell, _| cell.within(new_grid));
+        before - self.hashmap.len()
     }
 
-    /// Checks if the `Cell` is occupied. This is an alias for `contains_key` method
+    /// Renders `GridMap` into the `String` with one `char` per `Cell`, and `\n` after every row
     ///
-    /// # Panics
-    /// Panics, if the given `Cell` is not within the inner `Grid`
+    /// Occupied `Cell`s are rendered with `f`, and vacant `Cell`s are rendered as `empty`.
+    /// Rows go from top to bottom, and cells within the row go from left to right
     ///
     /// # Examples:
     ///
     /// ```
-    /// use grid_math::{Cell, Grid, GridMap};
+    /// use grid_math::{Cell, GridMap};
     ///
-    /// let grid = Grid::new(5, 5);
-    /// let cell = Cell::new(2, 3);
-    /// let mut map: GridMap<char> = GridMap::from(grid);
-    /// map.insert(cell, '#');
+    /// let mut map: GridMap<u8> = GridMap::new(4, 3);
+    /// map.insert(Cell::new(0, 0), 1);
+    /// map.insert(Cell::new(2, 1), 7);
     ///
-    /// assert!(map.occupied(cell));
-    /// assert!(!map.occupied(map.grid().start()));
+    /// let rendered = map.render('.', |value| char::from(b'0' + value));
+    /// assert_eq!(rendered, "1...\n..7.\n....\n");
     /// ```
-    pub fn occupied(&self, cell: Cell) -> bool {
-        cell.within_panic(self.grid);
-        self.contains_key(&cell)
+    pub fn render(&self, empty: char, f: impl Fn(&V) -> char) -> String {
+        let mut rendered = self.grid.render_ascii(
+            |cell| self.get(&cell).map_or(empty, &f).to_string(),
+            "",
+            "\n",
+        );
+        rendered.push('\n');
+        rendered
     }
 
-    /// Checks if the `Cell` is free
+    /// Returns an iterator over occupied `Cell`s and their values in `Grid` order
     ///
-    /// # Panics
-    /// Panics, if the given `Cell` is not within the inner `Grid`
+    /// Unlike iteration over the inner `HashMap`, the order is deterministic
     ///
     /// # Examples:
     ///
     /// ```
-    /// use grid_math::{Cell, Grid, GridMap};
+    /// use grid_math::{Cell, GridMap};
     ///
-    /// let grid = Grid::new(5, 5);
-    /// let cell = Cell::new(2, 3);
-    /// let mut map: GridMap<char> = GridMap::from(grid);
-    /// map.insert(cell, '#');
+    /// let mut map: GridMap<char> = GridMap::new(4, 4);
+    /// map.insert(Cell::new(3, 2), 'c');
+    /// map.insert(Cell::new(0, 3), 'd');
+    /// map.insert(Cell::new(2, 0), 'a');
+    /// map.insert(Cell::new(1, 2), 'b');
     ///
-    /// assert!(!map.vacant(cell));
-    /// assert!(map.vacant(map.grid().start()));
+    /// let ordered: Vec<(Cell, &char)> = map.iter_ordered().collect();
+    /// assert_eq!(ordered, vec![
+    ///     (Cell::new(2, 0), &'a'),
+    ///     (Cell::new(1, 2), &'b'),
+    ///     (Cell::new(3, 2), &'c'),
+    ///     (Cell::new(0, 3), &'d'),
+    /// ]);
+    ///
+    /// let occupied: Vec<Cell> = map.grid().cells().filter(|cell| map.contains_key(cell)).collect();
+    /// assert!(ordered.iter().map(|&(cell, _)| cell).eq(occupied));
     /// ```
-    pub fn vacant(&self, cell: Cell) -> bool {
-        cell.within_panic(self.grid);
-        !self.contains_key(&cell)
+    pub fn iter_ordered(&self) -> impl Iterator<Item = (Cell, &V)> {
+        self.grid
+            .cells()
+            .filter_map(|cell| self.get(&cell).map(|value| (cell, value)))
     }
 
-    /// Returns count of occupied `Cell`s
+    /// Removes every entry, whose value equals `blank`, and returns the number of removed entries
     ///
     /// # Examples:
     ///
     /// ```
-    /// use grid_math::{Cell, Grid, GridMap};
+    /// use grid_math::{Cell, GridMap};
     ///
-    /// let mut map: GridMap<char> = GridMap::new(5, 5);
-    /// map.insert(Cell::new(2, 3), '#');
-    /// map.insert(Cell::new(4, 1), '@');
+    /// let mut map: GridMap<char> = GridMap::new(3, 3);
+    /// map.fill('.');
+    /// map.insert(Cell::new(1, 1), '#');
+    /// map.insert(Cell::new(2, 0), '@');
     ///
-    /// assert_eq!(map.occupied_count(), 2);
-    /// ```
-    pub fn occupied_count(&self) -> u16 {
-        self.len() as u16
+    /// assert_eq!(map.compact(&'.'), 7);
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map.get(&Cell::new(1, 1)), Some(&'#'));
+    /// assert_eq!(map.compact(&'.'), 0);
+    /// ```
+    pub fn compact(&mut self, blank: &V) -> usize
+    where
+        V: PartialEq,
+    {
+        let len = self.hashmap.len();
+        self.hashmap.retain(|_, value| value != blank);
+        len - self.hashmap.len()
     }
 
-    /// Returns count of vacant `Cell`s
+    /// Creates new `GridMap` from the `(Cell, V)` pairs, where the inner `Grid` is the bounding box of all `Cell`s
+    ///
+    /// If the same `Cell` occurs more than once, the last value is kept
+    ///
+    /// # Panics
+    /// Panics if the iterator is empty, because the `Grid` can't be empty
+    /// Panics if the `Cell`s span 256 cells on any axis, because such `Grid` does not fit into the `u8` global positions
     ///
     /// # Examples:
     ///
     /// ```
     /// use grid_math::{Cell, Grid, GridMap};
     ///
-    /// let mut map: GridMap<char> = GridMap::new(5, 5);
-    /// map.insert(Cell::new(2, 3), '#');
-    /// map.insert(Cell::new(4, 1), '@');
+    /// let placements = vec![(Cell::new(3, 2), '#'), (Cell::new(7, 4), '@'), (Cell::new(5, 9), '%')];
+    /// let map = GridMap::from_cells(placements);
+    /// assert_eq!(map.grid(), Grid::from(((3, 2), (7, 9))));
+    /// assert_eq!(map.len(), 3);
+    /// assert_eq!(map.get(&Cell::new(7, 4)), Some(&'@'));
+    /// ```
     ///
-    /// assert_eq!(map.vacant_count(), 23);
+    /// ```should_panic
+    /// use grid_math::{Cell, GridMap};
+    ///
+    /// let map: GridMap<char> = GridMap::from_cells(Vec::new()); // panic!
     /// ```
-    pub fn vacant_count(&self) -> u16 {
-        self.grid.size() - self.occupied_count()
+    ///
+    /// ```should_panic
+    /// use grid_math::{Cell, GridMap};
+    ///
+    /// let map = GridMap::from_cells(vec![(Cell::new(0, 0), 1), (Cell::new(255, 9), 2)]); // panic!
+    /// ```
+    pub fn from_cells<I: IntoIterator<Item = (Cell, V)>>(iter: I) -> GridMap<V> {
+        let hashmap: HashMap<Cell, V> = iter.into_iter().collect();
+        let grid = match Grid::bounding_box(hashmap.keys().copied()) {
+            Ok(Some(grid)) => grid,
+            Ok(None) => panic!("can't create grid map from empty iterator"),
+            Err(_) => panic!("can't create grid map from cells spanning 256 cells on any axis"),
+        };
+        Self { grid, hashmap }
     }
 
-    /// Returns an iterator over every occupied `Cell`
+    /// Returns new `GridMap`, transposed across the main diagonal
+    ///
+    /// The inner `Grid` of the new `GridMap` is `grid.transpose()`,
+    /// and every entry is moved with `Cell::transpose`
     ///
     /// # Examples:
     ///
     /// ```
     /// use grid_math::{Cell, Grid, GridMap};
     ///
-    /// let mut map: GridMap<char> = GridMap::new(5, 5);
-    /// map.insert(Cell::new(2, 3), '#');
-    /// map.insert(Cell::new(4, 1), '@');
-    ///
-    /// assert_eq!(map.all_occupied().count(), 2);
-    /// ```
-    pub fn all_occupied(&self) -> Filter<Cells, impl FnMut(&Cell) -> bool> {
-        self.grid.cells().filter(|&cell| self.occupied(cell))
+    /// let mut map: GridMap<char> = GridMap::from(Grid::indented(4, 2, (1, 3)));
+    /// map.insert(Cell::new(4, 3), '#');
+    /// map.insert(Cell::new(2, 4), '@');
+    ///
+    /// let transposed = map.transposed();
+    /// assert_eq!(transposed.grid(), Grid::indented(2, 4, (3, 1)));
+    /// assert_eq!(transposed.get(&Cell::new(3, 4)), Some(&'#'));
+    /// assert_eq!(transposed.get(&Cell::new(4, 2)), Some(&'@'));
+    ///
+    /// let restored = transposed.transposed();
+    /// assert_eq!(restored.grid(), map.grid());
+    /// assert!(map.diff(&restored).is_empty());
+    /// ```
+    pub fn transposed(&self) -> GridMap<V>
+    where
+        V: Clone,
+    {
+        GridMap {
+            grid: self.grid.transpose(),
+            hashmap: self
+                .hashmap
+                .iter()
+                .map(|(cell, value)| (cell.transpose(), value.clone()))
+                .collect(),
+        }
     }
 
-    /// Returns an iterator over every vacant `Cell`
+    /// Returns every `Cell`, that is reachable from the `start` through orthogonal neighbors,
+    /// accepted by the `passable` predicate, in breadth-first order
+    ///
+    /// The predicate receives the `Cell` and its value, if any. The `start` itself must be accepted,
+    /// otherwise nothing is reachable. No `Cell` is visited twice, and the search never leaves the `Grid`
+    ///
+    /// # Panics
+    /// Panics if the `start` is not within the `GridMap`'s `Grid`
     ///
     /// # Examples:
     ///
     /// ```
-    /// use grid_math::{Cell, Grid, GridMap};
+    /// use grid_math::{Cell, GridMap};
     ///
-    /// let mut map: GridMap<char> = GridMap::new(5, 5);
-    /// map.insert(Cell::new(2, 3), '#');
-    /// map.insert(Cell::new(4, 1), '@');
+    /// let mut map: GridMap<char> = GridMap::new(4, 4);
+    /// // . # . .
+    /// // . # . .
+    /// // . # # #
+    /// // . . . .
+    /// for cell in [(1, 0), (1, 1), (1, 2), (2, 2), (3, 2)] {
+    ///     map.insert(Cell::new(cell.0, cell.1), '#');
+    /// }
+    /// let passable = |_, value: Option<&char>| value != Some(&'#');
     ///
-    /// assert_eq!(map.all_vacant().count(), 23);
+    /// let reachable = map.flood_fill(Cell::new(2, 0), passable);
+    /// assert_eq!(reachable, vec![
+    ///     Cell::new(2, 0),
+    ///     Cell::new(2, 1),
+    ///     Cell::new(3, 0),
+    ///     Cell::new(3, 1),
+    /// ]);
+    ///
+    /// let reachable = map.flood_fill(Cell::new(0, 0), passable);
+    /// assert_eq!(reachable.len(), 7);
+    /// assert!(reachable.contains(&Cell::new(3, 3)));
+    ///
+    /// assert!(map.flood_fill(Cell::new(1, 0), passable).is_empty()); // start is a wall
     /// ```
-    pub fn all_vacant(&self) -> Filter<Cells, impl FnMut(&Cell) -> bool> {
-        self.grid.cells().filter(|&cell| self.vacant(cell))
+    ///
+    /// ```should_panic
+    /// use grid_math::{Cell, GridMap};
+    ///
+    /// let map: GridMap<char> = GridMap::new(4, 4);
+    /// map.flood_fill(Cell::new(4, 0), |_, _| true); // panic!
+    /// ```
+    pub fn flood_fill(
+        &self,
+        start: Cell,
+        passable: impl Fn(Cell, Option<&V>) -> bool,
+    ) -> Vec<Cell> {
+        start.within_panic(self.grid);
+        if !passable(start, self.hashmap.get(&start)) {
+            return Vec::new();
+        }
+        let mut visited = HashSet::from([start]);
+        let mut queue = VecDeque::from([start]);
+        let mut reachable = Vec::new();
+        while let Some(cell) = queue.pop_front() {
+            reachable.push(cell);
+            for neighbor in cell.neighbors(self.grid) {
+                if !visited.contains(&neighbor) && passable(neighbor, self.hashmap.get(&neighbor)) {
+                    visited.insert(neighbor);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        reachable
     }
 
-    /// Returns first occupied `Cell`
+    /// Returns the shortest path from the `start` to the `goal` through orthogonal neighbors,
+    /// accepted by the `passable` predicate, or `None` if the `goal` is unreachable
     ///
-    /// # Note
-    /// This returns first `Cell` in `Grid` order, so (2, 3) will go after (4, 1)
+    /// The path includes both ends, and is found with A* search, using the Manhattan distance as the heuristic.
+    /// The predicate receives the `Cell` and its value, if any, and both ends must be accepted by it
+    ///
+    /// # Panics
+    /// Panics if the `start` or the `goal` is not within the `GridMap`'s `Grid`
     ///
     /// # Examples:
     ///
     /// ```
-    /// use grid_math::{Cell, Grid, GridMap};
+    /// use grid_math::{Cell, GridMap};
     ///
-    /// let mut map: GridMap<char> = GridMap::new(5, 5);
-    /// map.insert(Cell::new(2, 3), '#');
-    /// map.insert(Cell::new(4, 1), '@');
+    /// let mut map: GridMap<char> = GridMap::new(5, 4);
+    /// // . . # . .
+    /// // . . # . .
+    /// // . . # . .
+    /// // . . . . .
+    /// for depth in 0..3 {
+    ///     map.insert(Cell::new(2, depth), '#');
+    /// }
+    /// let passable = |_, value: Option<&char>| value != Some(&'#');
     ///
-    /// assert_eq!(map.first_occupied(), Some(Cell::new(4, 1)));
+    /// let path = map.astar(Cell::new(0, 0), Cell::new(4, 0), passable).unwrap();
+    /// assert_eq!(path.len(), 11);
+    /// assert_eq!(path.first(), Some(&Cell::new(0, 0)));
+    /// assert_eq!(path.last(), Some(&Cell::new(4, 0)));
+    /// assert!(path.windows(2).all(|pair| pair[0].manhattan_distance(pair[1]) == 1));
+    /// assert!(path.iter().all(|cell| map.get(cell).is_none()));
+    ///
+    /// let start = Cell::new(1, 1);
+    /// assert_eq!(map.astar(start, start, passable), Some(vec![start]));
+    ///
+    /// // goal is walled off:
+    /// map.insert(Cell::new(2, 3), '#');
+    /// assert_eq!(map.astar(Cell::new(0, 0), Cell::new(4, 0), passable), None);
     /// ```
-    pub fn first_occupied(&self) -> Option<Cell> {
-        self.all_occupied().next()
+    ///
+    /// ```should_panic
+    /// use grid_math::{Cell, GridMap};
+    ///
+    /// let map: GridMap<char> = GridMap::new(5, 4);
+    /// map.astar(Cell::new(0, 0), Cell::new(5, 0), |_, _| true); // panic!
+    /// ```
+    pub fn astar(
+        &self,
+        start: Cell,
+        goal: Cell,
+        passable: impl Fn(Cell, Option<&V>) -> bool,
+    ) -> Option<Vec<Cell>> {
+        start.within_panic(self.grid);
+        goal.within_panic(self.grid);
+        if !passable(start, self.hashmap.get(&start)) || !passable(goal, self.hashmap.get(&goal)) {
+            return None;
+        }
+        let mut frontier = BinaryHeap::from([Reverse((start.manhattan_distance(goal), start))]);
+        let mut costs: HashMap<Cell, u16> = HashMap::from([(start, 0)]);
+        let mut came_from: HashMap<Cell, Cell> = HashMap::new();
+        while let Some(Reverse((_, cell))) = frontier.pop() {
+            if cell == goal {
+                let mut path = vec![goal];
+                while let Some(&previous) = came_from.get(&path[path.len() - 1]) {
+                    path.push(previous);
+                }
+                path.reverse();
+                return Some(path);
+            }
+            let cost = costs[&cell] + 1;
+            for neighbor in cell.neighbors(self.grid) {
+                if costs.get(&neighbor).is_none_or(|&known| cost < known)
+                    && passable(neighbor, self.hashmap.get(&neighbor))
+                {
+                    costs.insert(neighbor, cost);
+                    came_from.insert(neighbor, cell);
+                    frontier.push(Reverse((
+                        cost + neighbor.manhattan_distance(goal),
+                        neighbor,
+                    )));
+                }
+            }
+        }
+        None
     }
 
-    /// Returns first vacant `Cell`
+    /// Returns orthogonal neighbors of the `Cell`, that are occupied, paired with their values
     ///
-    /// # Note
-    /// This returns first `Cell` in `Grid` order
+    /// This is `neighbor_entries` without vacant neighbors and `Heading`s,
+    /// neighbors go in the same order as in `Cell::neighbors`
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the `GridMap`'s `Grid`
     ///
     /// # Examples:
     ///
     /// ```
-    /// use grid_math::{Cell, Grid, GridMap};
+    /// use grid_math::{Cell, GridMap};
     ///
-    /// let mut map: GridMap<char> = GridMap::new(5, 5);
-    /// map.insert(Cell::new(2, 3), '#');
-    /// map.insert(Cell::new(4, 1), '@');
+    /// let mut map: GridMap<char> = GridMap::new(3, 3);
+    /// map.insert(Cell::new(1, 0), '#');
+    /// map.insert(Cell::new(0, 1), '@');
+    /// map.insert(Cell::new(2, 2), '%');
     ///
-    /// assert_eq!(map.first_vacant(), Some(Cell::new(0, 0)));
+    /// let neighbors = map.neighbors_values(Cell::new(1, 1));
+    /// assert_eq!(neighbors, vec![(Cell::new(1, 0), &'#'), (Cell::new(0, 1), &'@')]);
+    /// assert_eq!(map.neighbors_values(Cell::new(2, 0)), vec![(Cell::new(1, 0), &'#')]);
     /// ```
-    pub fn first_vacant(&self) -> Option<Cell> {
-        self.all_vacant().next()
+    ///
+    /// ```should_panic
+    /// use grid_math::{Cell, GridMap};
+    ///
+    /// let map: GridMap<char> = GridMap::new(3, 3);
+    /// map.neighbors_values(Cell::new(3, 3)); // panic!
+    /// ```
+    pub fn neighbors_values(&self, cell: Cell) -> Vec<(Cell, &V)> {
+        self.neighbor_entries(cell, false)
+            .into_iter()
+            .filter_map(|(_, neighbor, value)| Some((neighbor, value?)))
+            .collect()
     }
 
-    /// Returns random occupied `Cell`
+    /// Returns orthogonal and diagonal neighbors of the `Cell`, that are occupied, paired with their values
+    ///
+    /// This is `neighbor_entries` without vacant neighbors and `Heading`s,
+    /// neighbors go in the same order as in `Cell::neighbors_diagonal`
+    ///
+    /// # Panics
+    /// Panics if the `Cell` is not within the `GridMap`'s `Grid`
     ///
     /// # Examples:
     ///
     /// ```
-    /// use grid_math::{Cell, Grid, GridMap};
+    /// use grid_math::{Cell, GridMap};
+    ///
+    /// // blinker in the Game of Life:
+    /// // . . . . .
+    /// // . . # . .
+    /// // . . # . .
+    /// // . . # . .
+    /// // . . . . .
+    /// let mut map: GridMap<bool> = GridMap::new(5, 5);
+    /// for depth in 1..4 {
+    ///     map.insert(Cell::new(2, depth), true);
+    /// }
+    /// let alive = |cell| map.neighbors_values_diagonal(cell).len();
+    ///
+    /// assert_eq!(alive(Cell::new(2, 2)), 2); // survives
+    /// assert_eq!(alive(Cell::new(2, 1)), 1); // dies
+    /// assert_eq!(alive(Cell::new(1, 2)), 3); // is born
+    /// assert_eq!(alive(Cell::new(3, 2)), 3); // is born
+    /// assert_eq!(alive(Cell::new(0, 0)), 0);
+    ///
+    /// let born: Vec<Cell> = map
+    ///     .grid()
+    ///     .cells()
+    ///     .filter(|cell| !map.contains_key(cell) && alive(*cell) == 3)
+    ///     .collect();
+    /// assert_eq!(born, vec![Cell::new(1, 2), Cell::new(3, 2)]);
+    /// ```
     ///
-    /// let mut map: GridMap<char> = GridMap::new(5, 5);
-    /// map.insert(Cell::new(2, 3), '#');
-    /// map.insert(Cell::new(4, 1), '@');
+    /// ```should_panic
+    /// use grid_math::{Cell, GridMap};
     ///
-    /// assert_ne!(map.get(&map.random_occupied().unwrap()), None);
-    /// assert_ne!(map.get(&map.random_occupied().unwrap()), None);
-    /// assert_ne!(map.get(&map.random_occupied().unwrap()), None);
+    /// let map: GridMap<bool> = GridMap::new(5, 5);
+    /// map.neighbors_values_diagonal(Cell::new(5, 0)); // panic!
     /// ```
-    pub fn random_occupied(&self) -> Option<Cell> {
-        self.all_occupied().choose(&mut rand::rng())
+    pub fn neighbors_values_diagonal(&self, cell: Cell) -> Vec<(Cell, &V)> {
+        self.neighbor_entries(cell, true)
+            .into_iter()
+            .filter_map(|(_, neighbor, value)| Some((neighbor, value?)))
+            .collect()
     }
+}
 
-    /// Returns random vacant `Cell`
+impl GridMap<char> {
+    /// Encodes `GridMap` into the run-length-encoded `String` in `Grid` order
+    ///
+    /// Every run is written as `count` followed by `char`, where `count` is omitted for single `char`s.
+    /// Vacant `Cell`s are written as `empty`
+    ///
+    /// # Note
+    /// Occupied `Cell`s with value equal to `empty` will be decoded back as vacant ones
+    ///
+    /// # Panics
+    /// Panics, if `empty` or any value is an ASCII digit, because digits are reserved for run lengths
     ///
     /// # Examples:
     ///
     /// ```
-    /// use grid_math::{Cell, Grid, GridMap};
+    /// use grid_math::{Cell, GridMap};
     ///
-    /// let mut map: GridMap<char> = GridMap::new(5, 5);
-    /// map.insert(Cell::new(2, 3), '#');
-    /// map.insert(Cell::new(4, 1), '@');
+    /// let mut map: GridMap<char> = GridMap::new(10, 3);
+    /// map.grid().rows().next().unwrap().cells().for_each(|cell| {
+    ///     map.insert(cell, '#');
+    /// });
+    /// map.insert(Cell::new(9, 2), '@');
     ///
-    /// assert_eq!(map.get(&map.random_vacant().unwrap()), None);
-    /// assert_eq!(map.get(&map.random_vacant().unwrap()), None);
-    /// assert_eq!(map.get(&map.random_vacant().unwrap()), None);
+    /// let rle = map.to_rle('.');
+    /// assert_eq!(rle, "10#19.@");
+    ///
+    /// let decoded = GridMap::from_rle(map.grid(), &rle, '.').unwrap();
+    /// assert!(map.diff(&decoded).is_empty());
     /// ```
-    pub fn random_vacant(&self) -> Option<Cell> {
-        self.all_vacant().choose(&mut rand::rng())
+    pub fn to_rle(&self, empty: char) -> String {
+        let mut runs: Vec<(char, u16)> = Vec::new();
+        for cell in self.grid.cells() {
+            let value = self.get(&cell).copied().unwrap_or(empty);
+            if value.is_ascii_digit() {
+                panic!("can't encode digit with run-length encoding! cell:{cell}, value:{value}")
+            }
+            match runs.last_mut() {
+                Some((last, count)) if *last == value => *count += 1,
+                _ => runs.push((value, 1)),
+            }
+        }
+        runs.into_iter()
+            .map(|(value, count)| {
+                if count > 1 {
+                    format!("{count}{value}")
+                } else {
+                    value.to_string()
+                }
+            })
+            .collect()
+    }
+
+    /// Decodes `GridMap` from the run-length-encoded `String`, produced by the `to_rle` method
+    ///
+    /// Runs of `empty` are left vacant
+    ///
+    /// # Errors
+    /// Returns `ParseError::DimensionOverflow`, if the run length does not fit into `u16`
+    /// Returns `ParseError::RaggedOrInconsistent`, if the runs do not cover exactly every `Cell` of the `Grid`
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid, GridMap, ParseError};
+    ///
+    /// let grid = Grid::new(4, 2);
+    /// let map = GridMap::from_rle(grid, "3#4.@", '.').unwrap();
+    /// assert_eq!(map.len(), 4);
+    /// assert_eq!(map.get(&Cell::new(2, 0)), Some(&'#'));
+    /// assert_eq!(map.get(&Cell::new(3, 1)), Some(&'@'));
+    ///
+    /// assert_eq!(GridMap::from_rle(grid, "3#4.", '.').unwrap_err(), ParseError::RaggedOrInconsistent);
+    /// assert_eq!(GridMap::from_rle(grid, "99999#", '.').unwrap_err(), ParseError::DimensionOverflow);
+    /// ```
+    pub fn from_rle(grid: Grid, s: &str, empty: char) -> Result<GridMap<char>, ParseError> {
+        let mut map = GridMap::from(grid);
+        let mut cells = grid.cells();
+        let mut count: Option<u16> = None;
+        for value in s.chars() {
+            if let Some(digit) = value.to_digit(10) {
+                count = Some(
+                    count
+                        .unwrap_or(0)
+                        .checked_mul(10)
+                        .and_then(|count| count.checked_add(digit as u16))
+                        .ok_or(ParseError::DimensionOverflow)?,
+                );
+                continue;
+            }
+            let count = count.take().unwrap_or(1);
+            if count == 0 {
+                return Err(ParseError::RaggedOrInconsistent);
+            }
+            for _ in 0..count {
+                let cell = cells.next().ok_or(ParseError::RaggedOrInconsistent)?;
+                if value != empty {
+                    map.insert(cell, value);
+                }
+            }
+        }
+        if count.is_some() || cells.next().is_some() {
+            return Err(ParseError::RaggedOrInconsistent);
+        }
+        Ok(map)
     }
 }
 
@@ -0,0 +1,107 @@
+//! Implementations of `serde` traits for `GridMap`, enabled with the `serde` feature.
+
+use crate::{Cell, Grid, GridError, GridMap, ParseError};
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+/// Serialized representation of `Grid`, validated with `Grid::checked_from_bounds` on deserialization
+#[derive(serde::Deserialize)]
+#[serde(rename = "Grid")]
+pub(crate) struct RawGrid {
+    start: Cell,
+    end: Cell,
+}
+
+impl TryFrom<RawGrid> for Grid {
+    type Error = GridError;
+
+    /// implements validated deserialization for `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, Grid};
+    ///
+    /// let json = r#"{"start":{"global_width":1,"global_depth":1},"end":{"global_width":3,"global_depth":4}}"#;
+    /// let grid: Grid = serde_json::from_str(json).unwrap();
+    /// assert_eq!(grid, Grid::from((Cell::new(1, 1), Cell::new(3, 4))));
+    ///
+    /// let json = r#"{"start":{"global_width":5,"global_depth":5},"end":{"global_width":1,"global_depth":1}}"#;
+    /// let result: Result<Grid, _> = serde_json::from_str(json);
+    /// assert!(result.unwrap_err().to_string().starts_with("start cell overflows end cell!"));
+    /// ```
+    fn try_from(raw: RawGrid) -> Result<Self, Self::Error> {
+        Grid::checked_from_bounds(raw.start, raw.end)
+    }
+}
+
+/// Serialized representation of `GridMap`: the inner `Grid` and every occupied `Cell` in `Grid` order
+#[derive(serde::Deserialize)]
+#[serde(rename = "GridMap")]
+struct GridMapData<V> {
+    grid: Grid,
+    cells: Vec<(Cell, V)>,
+}
+
+impl<V: Serialize> Serialize for GridMap<V> {
+    /// implements serialization for `GridMap`, as the inner `Grid` and occupied `Cell`s in `Grid` order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::{Cell, GridMap};
+    ///
+    /// let mut map: GridMap<char> = GridMap::new(3, 3);
+    /// map.insert(Cell::new(2, 1), '@');
+    /// map.insert(Cell::new(0, 0), '#');
+    ///
+    /// let json = serde_json::to_string(&map).unwrap();
+    /// assert_eq!(
+    ///     json,
+    ///     r##"{"grid":{"start":{"global_width":0,"global_depth":0},"end":{"global_width":2,"global_depth":2}},"cells":[[{"global_width":0,"global_depth":0},"#"],[{"global_width":2,"global_depth":1},"@"]]}"##
+    /// );
+    ///
+    /// let restored: GridMap<char> = serde_json::from_str(&json).unwrap();
+    /// assert_eq!(restored.grid(), map.grid());
+    /// assert!(map.diff(&restored).is_empty());
+    /// ```
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let cells: Vec<(Cell, &V)> = self.iter_ordered().collect();
+        let mut state = serializer.serialize_struct("GridMap", 2)?;
+        state.serialize_field("grid", &self.grid)?;
+        state.serialize_field("cells", &cells)?;
+        state.end()
+    }
+}
+
+impl<'de, V: Deserialize<'de>> Deserialize<'de> for GridMap<V> {
+    /// implements deserialization for `GridMap`
+    ///
+    /// Returns an error with `ParseError::KeyOutsideGrid` message instead of panic,
+    /// if any of the `Cell`s is not within the `Grid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid_math::GridMap;
+    ///
+    /// let json = r##"{
+    ///     "grid": {"start": {"global_width": 0, "global_depth": 0}, "end": {"global_width": 2, "global_depth": 2}},
+    ///     "cells": [[{"global_width": 5, "global_depth": 0}, "#"]]
+    /// }"##;
+    /// let result: Result<GridMap<char>, _> = serde_json::from_str(json);
+    /// let error = result.unwrap_err().to_string();
+    /// assert!(error.starts_with("parsed key is not within grid! cell:(5, 0)"));
+    /// ```
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = GridMapData::<V>::deserialize(deserializer)?;
+        let mut map = GridMap::from(data.grid);
+        for (cell, value) in data.cells {
+            if !cell.within(data.grid) {
+                return Err(de::Error::custom(ParseError::KeyOutsideGrid(cell)));
+            }
+            map.hashmap.insert(cell, value);
+        }
+        Ok(map)
+    }
+}